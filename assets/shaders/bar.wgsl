@@ -1,7 +1,9 @@
 #import bevy_pbr::{
     mesh_view_bindings::view,
-    mesh_functions::get_world_from_local
+    mesh_functions::get_world_from_local,
+    prepass_utils
 }
+#import bevy_health_bar3d::bar::{Bar, bar_color}
 
 @group(#{MATERIAL_BIND_GROUP}) @binding(0)
 var<uniform> value_and_dimensions: vec4<f32>;
@@ -15,11 +17,45 @@ var<uniform> moderate_color: vec4<f32>;
 var<uniform> low_color: vec4<f32>;
 @group(#{MATERIAL_BIND_GROUP}) @binding(5)
 var<uniform> offset: vec4<f32>;
-#ifdef HAS_BORDER
 @group(#{MATERIAL_BIND_GROUP}) @binding(6)
 var<uniform> border_color: vec4<f32>;
+#ifdef IS_ICON
+@group(#{MATERIAL_BIND_GROUP}) @binding(7)
+var icon_texture: texture_2d<f32>;
+@group(#{MATERIAL_BIND_GROUP}) @binding(8)
+var icon_sampler: sampler;
 #endif
 
+const MAX_CAMERAS: u32 = 4u;
+const CAMERA_ACTIVE: u32 = 1u;
+const CAMERA_HIDDEN: u32 = 2u;
+const CAMERA_BILLBOARD: u32 = 4u;
+const CAMERA_BILLBOARD_SHIFT: u32 = 3u;
+const CAMERA_NO_EDGE_CLAMP: u32 = 32u;
+
+const BILLBOARD_SPHERICAL: u32 = 0u;
+const BILLBOARD_CYLINDRICAL: u32 = 1u;
+const BILLBOARD_FIXED: u32 = 2u;
+
+// Opacity of the occluded parts of x-ray bars
+const XRAY_OCCLUDED_ALPHA: f32 = 0.3;
+
+struct BarCamera {
+    viewport: vec4<f32>,
+    projection: vec2<f32>,
+    scale: f32,
+    // bit 0: active, bit 1: hidden, bit 2: billboard mode set, bits 3-4: billboard mode, bit 5: no edge clamp
+    flags: u32,
+};
+
+// Settings of the cameras, one per column. The first row holds the viewports, the second one the projections,
+// scales and flags.
+@group(#{MATERIAL_BIND_GROUP}) @binding(9)
+var cameras: texture_2d<f32>;
+// (margin, arrow_size, bar_width, bar_height)
+@group(#{MATERIAL_BIND_GROUP}) @binding(10)
+var<uniform> edge_clamp: vec4<f32>;
+
 struct Vertex {
     @builtin(instance_index) instance_index: u32,
     @location(0) position: vec3<f32>,
@@ -31,74 +67,193 @@ struct VertexOutput {
     @location(0) uv: vec2<f32>
 };
 
+// Returns the settings of the camera rendering the current view. Cameras are told apart by their viewport and projection.
+fn view_camera() -> BarCamera {
+    let projection = vec2<f32>(view.clip_from_view[0].x, view.clip_from_view[1].y);
+
+    for (var i = 0u; i < MAX_CAMERAS; i++) {
+        let settings = textureLoad(cameras, vec2<u32>(i, 1u), 0);
+        let camera = BarCamera(textureLoad(cameras, vec2<u32>(i, 0u), 0), settings.xy, settings.z, u32(settings.w));
+        if (camera.flags & CAMERA_ACTIVE) == 0u {
+            continue;
+        }
+
+        let same_viewport = all(abs(view.viewport - camera.viewport) < vec4<f32>(0.5));
+        let same_projection = all(abs(projection - camera.projection) <= abs(camera.projection) * 1e-4);
+        if same_viewport && same_projection {
+            return camera;
+        }
+    }
+
+    return BarCamera(vec4<f32>(0.), vec2<f32>(0.), 1., 0u);
+}
+
 @vertex
 fn vertex(vertex: Vertex) -> VertexOutput {
     var out: VertexOutput;
+    out.uv = vertex.uv;
+
+    let camera = view_camera();
+    if (camera.flags & CAMERA_HIDDEN) != 0u {
+        // all vertices outside of the clip volume, so that nothing is rasterized
+        out.clip_position = vec4<f32>(2., 2., 2., 1.);
+        return out;
+    }
+
+#ifdef CYLINDRICAL_BILLBOARD
+    var billboard_mode = BILLBOARD_CYLINDRICAL;
+#else ifdef FIXED_BILLBOARD
+    var billboard_mode = BILLBOARD_FIXED;
+#else
+    var billboard_mode = BILLBOARD_SPHERICAL;
+#endif
+    if (camera.flags & CAMERA_BILLBOARD) != 0u {
+        billboard_mode = (camera.flags >> CAMERA_BILLBOARD_SHIFT) & 3u;
+    }
 
     let clip_from_world = view.clip_from_world;
-    let camera_right = normalize(vec3<f32>(clip_from_world[0].x, clip_from_world[1].x, clip_from_world[2].x));
-    let camera_up = normalize(vec3<f32>(clip_from_world[0].y, clip_from_world[1].y, clip_from_world[2].y));
+    var right = normalize(vec3<f32>(clip_from_world[0].x, clip_from_world[1].x, clip_from_world[2].x));
+    var up = normalize(vec3<f32>(clip_from_world[0].y, clip_from_world[1].y, clip_from_world[2].y));
+
+    if billboard_mode == BILLBOARD_CYLINDRICAL {
+        right = normalize(vec3<f32>(right.x, 0., right.z));
+        up = vec3<f32>(0., 1., 0.);
+    } else if billboard_mode == BILLBOARD_FIXED {
+        right = vec3<f32>(1., 0., 0.);
+        up = vec3<f32>(0., 1., 0.);
+    }
 
+    let vertex_position = vertex.position.xy * camera.scale;
 #ifdef WORLD_SPACE_OFFSET
-    let billboard = camera_right * vertex.position.x + camera_up * vertex.position.y;
-    let world_space = billboard + offset.xyz;
+    // world space offsets are applied to the translation of the bar, so that bars are sorted by their actual position
+    let center = vec2<f32>(0.);
 #else
-    let world_space = camera_right * (vertex.position.x + offset.x) + camera_up * (vertex.position.y + offset.y);
+    let center = offset.xy;
 #endif
-    let position = view.clip_from_world * get_world_from_local(vertex.instance_index) * vec4<f32>(world_space, 1.);
+    let world_from_local = get_world_from_local(vertex.instance_index);
+    let world_space = right * (vertex_position.x + center.x) + up * (vertex_position.y + center.y);
+    out.clip_position = view.clip_from_world * world_from_local * vec4<f32>(world_space, 1.);
 
-    out.uv = vertex.uv;
-    out.clip_position = position;
+#ifdef EDGE_CLAMP
+    if (camera.flags & CAMERA_NO_EDGE_CLAMP) == 0u {
+        let clip_center = view.clip_from_world * world_from_local * vec4<f32>(right * center.x + up * center.y, 1.);
+        let scale = camera.scale * vec2<f32>(length(world_from_local[0].xyz), length(world_from_local[1].xyz));
+        let clamped = edge_clamped_position(clip_center, vertex.position.xy, scale);
+        if clamped.w > 0. {
+            out.clip_position = clamped;
+            return out;
+        }
+    }
+#endif
+#ifdef IS_ARROW
+    // arrows are only shown next to clamped bars
+    out.clip_position = vec4<f32>(2., 2., 2., 1.);
+#endif
 
     return out;
 }
 
+#ifdef EDGE_CLAMP
+// Returns the clip position of a vertex of a bar whose center is clamped to the edges of the viewport, or a w of 0 if the
+// center is on screen. The vertex position is relative to the center of the bar and scaled by the given scale.
+fn edge_clamped_position(clip_center: vec4<f32>, vertex_position: vec2<f32>, scale: vec2<f32>) -> vec4<f32> {
+    let margin = edge_clamp.x;
+    let arrow_size = edge_clamp.y;
+    let bar_size = edge_clamp.zw;
+
+    // size of a world unit in normalized device coordinates at the distance of the center. It is the same in pixels
+    // along both axes. Centers behind the camera are measured at a distance of 1.
+    let unit = vec2<f32>(view.clip_from_view[0].x, view.clip_from_view[1].y) / max(abs(clip_center.w), 1.);
+    let pixel = 2. / view.viewport.zw;
+    // room for the arrow is kept on all sides, as it can point in any direction
+    let half_extents = (0.5 * bar_size + arrow_size) * scale * unit;
+    let limit = max(vec2<f32>(1.) - margin * pixel - half_extents, vec2<f32>(0.));
+
+    // the projection of centers behind the camera is mirrored, they are pushed off-screen in the direction they are in
+    var entity_position = clip_center.xy * 1e6;
+    if clip_center.w > 0. {
+        entity_position = clip_center.xy / clip_center.w;
+    } else if all(entity_position == vec2<f32>(0.)) {
+        entity_position = vec2<f32>(0., -1.);
+    }
+
+    if all(abs(entity_position) <= limit) {
+        return vec4<f32>(0.);
+    }
+
+    // point where the line from the center of the viewport to the entity leaves the clamped area
+    let to_edge = min(limit.x / max(abs(entity_position.x), 1e-6), limit.y / max(abs(entity_position.y), 1e-6));
+    let position = entity_position * to_edge;
+
+#ifdef IS_ARROW
+    // direction in world units, which are of the same size in pixels along both axes
+    let direction = normalize((entity_position - position) / unit);
+    let half_bar = 0.5 * bar_size;
+    let to_bar_edge = min(half_bar.x / max(abs(direction.x), 1e-6), half_bar.y / max(abs(direction.y), 1e-6));
+    let rotated = vec2<f32>(
+        vertex_position.x * direction.x - vertex_position.y * direction.y,
+        vertex_position.x * direction.y + vertex_position.y * direction.x,
+    );
+    let local = direction * (to_bar_edge + 0.5 * arrow_size) + rotated;
+#else
+    let local = vertex_position;
+#endif
+
+    // drawn at the near plane, in front of all other geometry
+    return vec4<f32>(position + local * scale * unit, 1., 1.);
+}
+#endif
+
 struct FragmentInput {
+     @builtin(position) position: vec4<f32>,
      @location(0) uv: vec2<f32>
 };
 
+#ifdef IS_VERTICAL
+const VERTICAL: bool = true;
+#else
+const VERTICAL: bool = false;
+#endif
+#ifdef LOW_BAND_PATTERN
+const STRIPED_LOW_BAND: bool = true;
+#else
+const STRIPED_LOW_BAND: bool = false;
+#endif
+
+// Returns the bar described by the material
+fn material_bar() -> Bar {
+    return Bar(
+        value_and_dimensions,
+        background_color,
+        high_color,
+        moderate_color,
+        low_color,
+        border_color,
+        VERTICAL,
+        STRIPED_LOW_BAND,
+    );
+}
+
 @fragment
 fn fragment(in: FragmentInput) -> @location(0) vec4<f32> {
-    let value = value_and_dimensions.x;
-    #ifdef HAS_BORDER
-      let resolution = value_and_dimensions.yz;
-      let border_width = value_and_dimensions.w;
-      let border_y = border_width / resolution.y;
-      let border_x = border_width / resolution.x;
-
-      if in.uv.y < border_y || in.uv.y > 1. - border_y || in.uv.x < border_x || in.uv.x > 1. - border_x {
-          return border_color;
-      }
-    #endif
-
-    #ifdef IS_VERTICAL
-      let val = 1.0 - value;
-      if in.uv.y < val {
-          return background_color;
-      }
-
-      if val > 0.6 {
-          return low_color;
-      }
-
-      if val > 0.2 {
-          return moderate_color;
-      }
-
-      return high_color;
-    #else
-      if in.uv.x > value {
-          return background_color;
-      }
-
-      if value < 0.4 {
-          return low_color;
-      }
-
-      if value < 0.8 {
-          return moderate_color;
-      }
-
-      return high_color;
-    #endif
+    let color = fragment_color(in);
+#ifdef XRAY_OCCLUSION
+#ifdef DEPTH_PREPASS
+    // depth is reversed, fragments behind opaque geometry have a smaller depth than the prepass
+    if in.position.z < prepass_utils::prepass_depth(in.position, 0u) {
+        return vec4<f32>(color.rgb, color.a * XRAY_OCCLUDED_ALPHA);
+    }
+#endif
+#endif
+    return color;
+}
+
+fn fragment_color(in: FragmentInput) -> vec4<f32> {
+#ifdef IS_ARROW
+    return high_color;
+#else ifdef IS_ICON
+    return textureSample(icon_texture, icon_sampler, in.uv);
+#else
+    return bar_color(material_bar(), in.uv);
+#endif
 }
@@ -2,6 +2,7 @@
     mesh2d_view_bindings::view,
     mesh2d_functions::get_world_from_local
 }
+#import bevy_health_bar3d::bar::{Bar, bar_color}
 
 @group(#{MATERIAL_BIND_GROUP}) @binding(0)
 var<uniform> value_and_dimensions: vec4<f32>;
@@ -15,9 +16,13 @@ var<uniform> moderate_color: vec4<f32>;
 var<uniform> low_color: vec4<f32>;
 @group(#{MATERIAL_BIND_GROUP}) @binding(5)
 var<uniform> offset: vec4<f32>;
-#ifdef HAS_BORDER
 @group(#{MATERIAL_BIND_GROUP}) @binding(6)
 var<uniform> border_color: vec4<f32>;
+#ifdef IS_ICON
+@group(#{MATERIAL_BIND_GROUP}) @binding(7)
+var icon_texture: texture_2d<f32>;
+@group(#{MATERIAL_BIND_GROUP}) @binding(8)
+var icon_sampler: sampler;
 #endif
 
 struct Vertex {
@@ -35,8 +40,14 @@ struct VertexOutput {
 fn vertex(vertex: Vertex) -> VertexOutput {
     var out: VertexOutput;
 
+#ifdef WORLD_SPACE_OFFSET
+    // world space offsets are applied to the translation of the bar, so that bars are sorted by their actual position
+    let center = vec2<f32>(0.);
+#else
+    let center = offset.xy;
+#endif
     let world_from_local = get_world_from_local(vertex.instance_index);
-    let world_position = world_from_local * vec4<f32>(vertex.position.x + offset.x, vertex.position.y + offset.y, vertex.position.z, 1.);
+    let world_position = world_from_local * vec4<f32>(vertex.position.xy + center, vertex.position.z, 1.);
     out.clip_position = view.clip_from_world * world_position;
     out.uv = vertex.uv;
 
@@ -47,49 +58,37 @@ struct FragmentInput {
      @location(0) uv: vec2<f32>
 };
 
-@fragment
-fn fragment(in: FragmentInput) -> @location(0) vec4<f32> {
-    let value = value_and_dimensions.x;
-    #ifdef HAS_BORDER
-      let resolution = value_and_dimensions.yz;
-      let border_width = value_and_dimensions.w;
-      let border_y = border_width / resolution.y;
-      let border_x = border_width / resolution.x;
-
-      if in.uv.y < border_y || in.uv.y > 1. - border_y || in.uv.x < border_x || in.uv.x > 1. - border_x {
-          return border_color;
-      }
-    #endif
-
-    #ifdef IS_VERTICAL
-      let val = 1.0 - value;
-      if in.uv.y < val {
-          return background_color;
-      }
-
-      if val > 0.6 {
-          return low_color;
-      }
-
-      if val > 0.2 {
-          return moderate_color;
-      }
-
-      return high_color;
-    #else
-      if in.uv.x > value {
-          return background_color;
-      }
-
-      if value < 0.4 {
-          return low_color;
-      }
+#ifdef IS_VERTICAL
+const VERTICAL: bool = true;
+#else
+const VERTICAL: bool = false;
+#endif
+#ifdef LOW_BAND_PATTERN
+const STRIPED_LOW_BAND: bool = true;
+#else
+const STRIPED_LOW_BAND: bool = false;
+#endif
 
-      if value < 0.8 {
-          return moderate_color;
-      }
+// Returns the bar described by the material
+fn material_bar() -> Bar {
+    return Bar(
+        value_and_dimensions,
+        background_color,
+        high_color,
+        moderate_color,
+        low_color,
+        border_color,
+        VERTICAL,
+        STRIPED_LOW_BAND,
+    );
+}
 
-      return high_color;
-    #endif
+@fragment
+fn fragment(in: FragmentInput) -> @location(0) vec4<f32> {
+#ifdef IS_ICON
+    return textureSample(icon_texture, icon_sampler, in.uv);
+#else
+    return bar_color(material_bar(), in.uv);
+#endif
 }
 
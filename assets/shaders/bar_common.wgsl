@@ -0,0 +1,52 @@
+#define_import_path bevy_health_bar3d::bar
+
+// Values below these thresholds are drawn in the low and moderate colors, see `BarBand`
+const LOW_THRESHOLD: f32 = 0.4;
+const HIGH_THRESHOLD: f32 = 0.8;
+
+// Everything needed to color a bar, as stored in the bar materials
+struct Bar {
+    // (value, width, height, border_width)
+    value_and_dimensions: vec4<f32>,
+    background_color: vec4<f32>,
+    high_color: vec4<f32>,
+    moderate_color: vec4<f32>,
+    low_color: vec4<f32>,
+    border_color: vec4<f32>,
+    vertical: bool,
+    low_band_pattern: bool,
+};
+
+// Returns how far along the bar a point is, from 0 at its empty to 1 at its full end.
+// Vertical bars fill up from the bottom, where the uv y coordinate is 1.
+fn fill_position(uv: vec2<f32>, vertical: bool) -> f32 {
+    return select(uv.x, 1. - uv.y, vertical);
+}
+
+// Whether a point is on the border of a bar of the given size
+fn is_border(uv: vec2<f32>, size: vec2<f32>, border_width: f32) -> bool {
+    let border = border_width / size;
+    return any(uv < border) || any(uv > vec2<f32>(1.) - border);
+}
+
+// Returns the color of the band the value of the bar falls into
+fn band_color(bar: Bar, uv: vec2<f32>) -> vec4<f32> {
+    let value = bar.value_and_dimensions.x;
+    let size = bar.value_and_dimensions.yz;
+
+    // diagonal stripes, so that the low band can be told apart without relying on color alone
+    let period = min(size.x, size.y) * 0.5;
+    let striped = bar.low_band_pattern && fract(dot(uv, size) / period) < 0.5;
+    let low_color = select(bar.low_color, vec4<f32>(bar.low_color.rgb * 0.5, bar.low_color.a), striped);
+
+    let color = select(bar.high_color, bar.moderate_color, value < HIGH_THRESHOLD);
+    return select(color, low_color, value < LOW_THRESHOLD);
+}
+
+// Returns the color of a bar at the given uv coordinate
+fn bar_color(bar: Bar, uv: vec2<f32>) -> vec4<f32> {
+    let filled = fill_position(uv, bar.vertical) <= bar.value_and_dimensions.x;
+    let color = select(bar.background_color, band_color(bar, uv), filled);
+    let border = is_border(uv, bar.value_and_dimensions.yz, bar.value_and_dimensions.w);
+    return select(color, bar.border_color, border);
+}
@@ -0,0 +1,107 @@
+use bevy::pbr::*;
+use bevy::prelude::*;
+
+use bevy_health_bar3d::prelude::{
+    BarAccessibility, BarPalette, BarSettings, HealthBarPlugin, Stat,
+};
+
+#[derive(TypePath)]
+struct HealthStat;
+
+#[derive(TypePath)]
+struct StaminaStat;
+
+type Health = Stat<HealthStat>;
+type Stamina = Stat<StaminaStat>;
+
+const PALETTES: [Option<BarPalette>; 5] = [
+    None,
+    Some(BarPalette::Deuteranopia),
+    Some(BarPalette::Protanopia),
+    Some(BarPalette::Tritanopia),
+    Some(BarPalette::HighContrast),
+];
+
+fn main() {
+    App::new()
+        .register_type::<Health>()
+        .register_type::<Stamina>()
+        .add_plugins((
+            DefaultPlugins,
+            HealthBarPlugin::<Health>::default(),
+            HealthBarPlugin::<Stamina>::default(),
+        ))
+        .add_systems(Startup, setup)
+        .add_systems(Update, update_accessibility)
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let radius = 0.2;
+
+    (0..3).for_each(|i| {
+        commands.spawn((
+            Mesh3d(meshes.add(Sphere { radius })),
+            MeshMaterial3d(materials.add(Color::srgba(0.8, 0.8, 0.8, 1.))),
+            Transform::from_xyz(-1. + i as f32, 1., 0.),
+            Health::new(90. - 30. * i as f32, 100.),
+            BarSettings::<Health> {
+                offset: radius * 1.5 + 0.1,
+                width: radius * 3.,
+                ..default()
+            },
+            Stamina::new(20. + 30. * i as f32, 100.),
+            BarSettings::<Stamina> {
+                offset: radius * 1.5,
+                width: radius * 3.,
+                ..default()
+            },
+        ));
+    });
+
+    commands.spawn((
+        Text::new("Press space to cycle through the palettes, P to toggle the low band pattern"),
+        Node {
+            position_type: PositionType::Absolute,
+            top: px(12),
+            left: px(12),
+            ..default()
+        },
+    ));
+
+    // Light
+    commands.spawn((
+        PointLight {
+            intensity: 1500.0,
+            shadows_enabled: true,
+            ..Default::default()
+        },
+        Transform::from_xyz(4.0, 8.0, 4.0),
+    ));
+
+    // Camera
+    commands.spawn((
+        Camera3d::default(),
+        Msaa::Sample4,
+        Transform::from_xyz(0., 1.5, 4.0).looking_at(Vec3::Y, Vec3::Y),
+    ));
+}
+
+fn update_accessibility(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut accessibility: ResMut<BarAccessibility>,
+    mut palette_index: Local<usize>,
+) {
+    if keyboard_input.just_pressed(KeyCode::Space) {
+        *palette_index = (*palette_index + 1) % PALETTES.len();
+        accessibility.palette = PALETTES[*palette_index];
+    }
+
+    if keyboard_input.just_pressed(KeyCode::KeyP) {
+        accessibility.low_band_pattern = !accessibility.low_band_pattern;
+    }
+}
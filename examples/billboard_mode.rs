@@ -0,0 +1,108 @@
+use bevy::pbr::*;
+use bevy::prelude::*;
+
+use bevy_health_bar3d::prelude::{
+    BarBillboardMode, BarHeight, BarSettings, HealthBarPlugin, Percentage,
+};
+
+#[derive(Component, Reflect)]
+struct Health {
+    max: f32,
+    current: f32,
+}
+
+impl Percentage for Health {
+    fn value(&self) -> f32 {
+        self.current / self.max
+    }
+}
+
+#[derive(Component)]
+struct Rotate;
+
+fn main() {
+    App::new()
+        .register_type::<Health>()
+        .add_plugins((DefaultPlugins, HealthBarPlugin::<Health>::default()))
+        .add_systems(Startup, setup)
+        .add_systems(Update, rotate)
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // Ground
+    commands.spawn((
+        Mesh3d(meshes.add(Plane3d::default().mesh().size(6.0, 6.0))),
+        MeshMaterial3d(materials.add(Color::srgba(0.3, 0.5, 0.3, 1.))),
+    ));
+
+    let radius = 0.3;
+    let modes = [BarBillboardMode::Spherical, BarBillboardMode::Cylindrical];
+
+    modes
+        .into_iter()
+        .enumerate()
+        .for_each(|(i, billboard_mode)| {
+            commands.spawn((
+                Mesh3d(meshes.add(Sphere { radius })),
+                MeshMaterial3d(materials.add(Color::srgba(1., 0.2, 0.2, 1.))),
+                Transform::from_xyz(-1.5 + 1.5 * i as f32, radius, 0.),
+                Health {
+                    max: 10.,
+                    current: 7.,
+                },
+                BarSettings::<Health> {
+                    offset: radius * 1.5,
+                    width: radius * 2.,
+                    billboard_mode,
+                    ..default()
+                },
+            ));
+        });
+
+    // A vehicle with a fixed bar, rotating with it
+    commands.spawn((
+        Mesh3d(meshes.add(Cuboid::new(1., 0.4, 0.6))),
+        MeshMaterial3d(materials.add(Color::srgba(0.2, 0.3, 0.8, 1.))),
+        Transform::from_xyz(1.5, 0.2, 0.),
+        Rotate,
+        Health {
+            max: 10.,
+            current: 4.,
+        },
+        BarSettings::<Health> {
+            offset: 0.4,
+            width: 0.8,
+            height: BarHeight::Static(0.1),
+            billboard_mode: BarBillboardMode::Fixed,
+            ..default()
+        },
+    ));
+
+    // Light
+    commands.spawn((
+        PointLight {
+            intensity: 1500.0,
+            shadows_enabled: true,
+            ..Default::default()
+        },
+        Transform::from_xyz(4.0, 8.0, 4.0),
+    ));
+
+    // Top-down camera
+    commands.spawn((
+        Camera3d::default(),
+        Msaa::Sample4,
+        Transform::from_xyz(0., 6., 3.).looking_at(Vec3::ZERO, Vec3::Y),
+    ));
+}
+
+fn rotate(time: Res<Time>, mut query: Query<&mut Transform, With<Rotate>>) {
+    query.iter_mut().for_each(|mut transform| {
+        transform.rotate_y(0.5 * time.delta_secs());
+    });
+}
@@ -0,0 +1,121 @@
+use bevy::camera::Viewport;
+use bevy::pbr::*;
+use bevy::prelude::*;
+
+use bevy_health_bar3d::prelude::{
+    BarBillboardMode, BarSettings, HealthBarCameraSettings, HealthBarPlugin, Percentage,
+};
+
+#[derive(Component, Reflect)]
+struct Health {
+    max: f32,
+    current: f32,
+}
+
+impl Percentage for Health {
+    fn value(&self) -> f32 {
+        self.current / self.max
+    }
+}
+
+#[derive(Component)]
+struct CloseUpCamera;
+
+fn main() {
+    App::new()
+        .register_type::<Health>()
+        .add_plugins((DefaultPlugins, HealthBarPlugin::<Health>::default()))
+        .add_systems(Startup, setup)
+        .add_systems(Update, toggle_bars)
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // Ground
+    commands.spawn((
+        Mesh3d(meshes.add(Plane3d::default().mesh().size(10.0, 10.0))),
+        MeshMaterial3d(materials.add(Color::srgba(0.3, 0.5, 0.3, 1.))),
+    ));
+
+    let radius = 0.2;
+    (0..9).for_each(|i| {
+        let (x, z) = ((i % 3) as f32 - 1., (i / 3) as f32 - 1.);
+        commands.spawn((
+            Mesh3d(meshes.add(Sphere { radius })),
+            MeshMaterial3d(materials.add(Color::srgba(1., 0.2, 0.2, 1.))),
+            Transform::from_xyz(x * 2., radius, z * 2.),
+            Health {
+                max: 10.,
+                current: 1. + i as f32,
+            },
+            BarSettings::<Health> {
+                offset: radius * 1.5,
+                width: radius * 2.,
+                ..default()
+            },
+        ));
+    });
+
+    commands.spawn((
+        Text::new("Press H to hide the bars in the close-up camera"),
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: px(12),
+            left: px(12),
+            ..default()
+        },
+    ));
+
+    // Light
+    commands.spawn((
+        PointLight {
+            intensity: 1500.0,
+            shadows_enabled: true,
+            ..Default::default()
+        },
+        Transform::from_xyz(4.0, 8.0, 4.0),
+    ));
+
+    // Close-up Camera
+    commands.spawn((
+        Camera3d::default(),
+        Msaa::Sample4,
+        Transform::from_xyz(0., 1.5, 4.0).looking_at(Vec3::ZERO, Vec3::Y),
+        HealthBarCameraSettings::default(),
+        CloseUpCamera,
+    ));
+
+    // Strategic Camera, bars are scaled up and stay upright to remain readable from far away
+    commands.spawn((
+        Camera3d::default(),
+        Camera {
+            order: 1,
+            viewport: Some(Viewport {
+                physical_position: UVec2::new(16, 16),
+                physical_size: UVec2::new(320, 240),
+                ..default()
+            }),
+            ..default()
+        },
+        Msaa::Sample4,
+        Transform::from_xyz(0., 12., 6.).looking_at(Vec3::ZERO, Vec3::Y),
+        HealthBarCameraSettings {
+            scale: 3.,
+            billboard: Some(BarBillboardMode::Cylindrical),
+            ..default()
+        },
+    ));
+}
+
+fn toggle_bars(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut settings: Single<&mut HealthBarCameraSettings, With<CloseUpCamera>>,
+) {
+    if keyboard_input.just_pressed(KeyCode::KeyH) {
+        settings.hidden = !settings.hidden;
+    }
+}
@@ -0,0 +1,118 @@
+use bevy::pbr::*;
+use bevy::prelude::*;
+
+use bevy_health_bar3d::prelude::{
+    BarHeight, BarOffsetMode, BarSettings, ColorScheme, ForegroundColor, HealthBarPlugin,
+    Percentage,
+};
+
+#[derive(Component, Reflect)]
+struct Health {
+    max: f32,
+    current: f32,
+}
+
+impl Percentage for Health {
+    fn value(&self) -> f32 {
+        self.current / self.max
+    }
+}
+
+#[derive(Component)]
+struct OrbitCamera;
+
+fn main() {
+    App::new()
+        .register_type::<Health>()
+        .add_plugins((DefaultPlugins, HealthBarPlugin::<Health>::default()))
+        .insert_resource(
+            ColorScheme::<Health>::new()
+                .foreground_color(ForegroundColor::Static(Color::srgba(0.2, 0.8, 0.2, 0.8)))
+                .background_color(Color::srgba(0., 0., 0., 0.6)),
+        )
+        .add_systems(Startup, setup)
+        .add_systems(Update, orbit)
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // Ground
+    commands.spawn((
+        Mesh3d(meshes.add(Plane3d::default().mesh().size(10.0, 10.0))),
+        MeshMaterial3d(materials.add(Color::srgba(0.3, 0.5, 0.3, 1.))),
+    ));
+
+    let radius = 0.15;
+    let mesh = meshes.add(Sphere { radius });
+    let material = materials.add(Color::srgba(1., 0.2, 0.2, 1.));
+
+    // A crowd of units standing so close that their semi-transparent bars overlap
+    (0..100).for_each(|i| {
+        let (x, z) = ((i % 10) as f32 - 4.5, (i / 10) as f32 - 4.5);
+        commands.spawn((
+            Mesh3d(mesh.clone()),
+            MeshMaterial3d(material.clone()),
+            Transform::from_xyz(x * 0.3, radius, z * 0.3),
+            Health {
+                max: 10.,
+                current: 1. + (i % 10) as f32,
+            },
+            BarSettings::<Health> {
+                offset: radius * 2.,
+                offset_mode: BarOffsetMode::WorldSpace,
+                width: radius * 4.,
+                height: BarHeight::Static(0.08),
+                ..default()
+            },
+        ));
+    });
+
+    // The leader's bar is drawn on top of the bars around it
+    commands.spawn((
+        Mesh3d(meshes.add(Sphere {
+            radius: radius * 1.5,
+        })),
+        MeshMaterial3d(materials.add(Color::srgba(0.2, 0.3, 0.8, 1.))),
+        Transform::from_xyz(0., radius * 1.5, 0.),
+        Health {
+            max: 10.,
+            current: 8.,
+        },
+        BarSettings::<Health> {
+            offset: radius * 3.,
+            offset_mode: BarOffsetMode::WorldSpace,
+            width: radius * 6.,
+            height: BarHeight::Static(0.1),
+            depth_bias: 10.,
+            ..default()
+        },
+    ));
+
+    // Light
+    commands.spawn((
+        PointLight {
+            intensity: 1500.0,
+            shadows_enabled: true,
+            ..Default::default()
+        },
+        Transform::from_xyz(4.0, 8.0, 4.0),
+    ));
+
+    // Camera
+    commands.spawn((
+        Camera3d::default(),
+        Msaa::Sample4,
+        Transform::from_xyz(0., 2., 5.).looking_at(Vec3::ZERO, Vec3::Y),
+        OrbitCamera,
+    ));
+}
+
+fn orbit(time: Res<Time>, mut query: Query<&mut Transform, With<OrbitCamera>>) {
+    query.iter_mut().for_each(|mut transform| {
+        transform.rotate_around(Vec3::ZERO, Quat::from_rotation_y(0.2 * time.delta_secs()));
+    });
+}
@@ -11,7 +11,8 @@ use std::f32::consts::PI;
 use std::time::Duration;
 
 use bevy_health_bar3d::prelude::{
-    BarHeight, BarSettings, ColorScheme, ForegroundColor, HealthBarPlugin, Percentage,
+    BarAnchor, BarAutoPlacement, BarHeight, BarOffsetMode, BarSettings, ColorScheme,
+    ForegroundColor, HealthBarPlugin, Percentage,
 };
 
 #[derive(Component, Reflect)]
@@ -108,14 +109,15 @@ fn setup(
             max: 10.,
             current: 8.,
         },
+        // bars are placed above the bounds of the model, the health bar on top of the distance bar
         BarSettings::<Distance> {
-            offset: 15.,
+            auto_placement: Some(BarAutoPlacement::new(0.5)),
             height: BarHeight::Static(1.),
             width: 10.,
             ..default()
         },
         BarSettings::<Health> {
-            offset: 17.,
+            auto_placement: Some(BarAutoPlacement::new(2.)),
             height: BarHeight::Static(1.),
             width: 10.,
             ..default()
@@ -129,8 +131,11 @@ fn setup(
             max: 10.,
             current: 10.,
         },
+        // the bar follows the head bone of the model
         BarSettings::<Health> {
-            offset: 17.,
+            anchor: Some(BarAnchor::Name("Head".into())),
+            offset: 4.,
+            offset_mode: BarOffsetMode::WorldSpace,
             height: BarHeight::Static(1.),
             width: 10.,
             ..default()
@@ -4,33 +4,16 @@ use bevy_inspector_egui::bevy_egui::EguiPlugin;
 use bevy_inspector_egui::quick::WorldInspectorPlugin;
 
 use bevy_health_bar3d::configuration::ForegroundColor;
-use bevy_health_bar3d::prelude::{
-    BarHeight, BarSettings, ColorScheme, HealthBarPlugin, Percentage,
-};
+use bevy_health_bar3d::prelude::{BarHeight, BarSettings, ColorScheme, HealthBarPlugin, Stat};
 
-#[derive(Component, Reflect)]
-struct Mana {
-    max: f32,
-    current: f32,
-}
+#[derive(TypePath)]
+struct ManaStat;
 
-impl Percentage for Mana {
-    fn value(&self) -> f32 {
-        self.current / self.max
-    }
-}
+#[derive(TypePath)]
+struct HealthStat;
 
-#[derive(Component, Reflect)]
-struct Health {
-    max: f32,
-    current: f32,
-}
-
-impl Percentage for Health {
-    fn value(&self) -> f32 {
-        self.current / self.max
-    }
-}
+type Mana = Stat<ManaStat>;
+type Health = Stat<HealthStat>;
 
 fn main() {
     App::new()
@@ -67,20 +50,14 @@ fn setup(
             Mesh3d(meshes.add(Sphere { radius })),
             MeshMaterial3d(materials.add(Color::srgba(1., 0.2, 0.2, 1.))),
             Transform::from_xyz(2. * radius, 0.4 + i as f32 / 2., 0.0),
-            Health {
-                max: 10.,
-                current: value,
-            },
+            Health::new(value, 10.),
             BarSettings::<Health> {
                 offset,
                 width: bar_width,
                 height: BarHeight::Static(bar_height),
                 ..default()
             },
-            Mana {
-                max: 10.,
-                current: values[2 - i],
-            },
+            Mana::new(values[2 - i], 10.),
             BarSettings::<Mana> {
                 offset: offset + bar_height + bar_height / 5.,
                 width: bar_width,
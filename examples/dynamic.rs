@@ -2,7 +2,9 @@ use bevy::prelude::*;
 use bevy_inspector_egui::bevy_egui::EguiPlugin;
 use bevy_inspector_egui::quick::WorldInspectorPlugin;
 
-use bevy_health_bar3d::prelude::{BarSettings, HealthBarPlugin, Percentage};
+use bevy_health_bar3d::prelude::{
+    BarEmptied, BarSettings, BarThresholdCrossed, HealthBarPlugin, Percentage,
+};
 
 #[derive(Component, Reflect)]
 struct Health {
@@ -26,7 +28,7 @@ fn main() {
             HealthBarPlugin::<Health>::default(),
         ))
         .add_systems(Startup, setup)
-        .add_systems(Update, update_health)
+        .add_systems(Update, (update_health, log_health_messages))
         .run();
 }
 
@@ -79,3 +81,19 @@ fn update_health(time: Res<Time>, mut query: Query<&mut Health>) {
         }
     })
 }
+
+fn log_health_messages(
+    mut threshold_crossed: MessageReader<BarThresholdCrossed<Health>>,
+    mut emptied: MessageReader<BarEmptied<Health>>,
+) {
+    for message in threshold_crossed.read() {
+        info!(
+            "{:?} health dropped from {:?} to {:?}",
+            message.entity, message.from, message.to
+        );
+    }
+
+    for message in emptied.read() {
+        info!("{:?} ran out of health", message.entity);
+    }
+}
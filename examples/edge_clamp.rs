@@ -0,0 +1,126 @@
+use bevy::camera::Viewport;
+use bevy::color::palettes::css::*;
+use bevy::pbr::*;
+use bevy::prelude::*;
+
+use bevy_health_bar3d::prelude::{
+    BarEdgeClamp, BarHeight, BarOcclusion, BarSettings, HealthBarCameraSettings, HealthBarPlugin,
+    Percentage,
+};
+
+#[derive(Component, Reflect)]
+struct Health {
+    max: f32,
+    current: f32,
+}
+
+impl Percentage for Health {
+    fn value(&self) -> f32 {
+        self.current / self.max
+    }
+}
+
+#[derive(Component)]
+struct PlayerCamera;
+
+fn main() {
+    App::new()
+        .register_type::<Health>()
+        .add_plugins((DefaultPlugins, HealthBarPlugin::<Health>::default()))
+        .add_systems(Startup, setup)
+        .add_systems(Update, look_around)
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // Ground
+    commands.spawn((
+        Mesh3d(meshes.add(Plane3d::default().mesh().size(30.0, 30.0))),
+        MeshMaterial3d(materials.add(Color::srgba(0.3, 0.5, 0.3, 1.))),
+    ));
+
+    // Boss, its bar stays at the edge of the screen with an arrow pointing towards it
+    commands.spawn((
+        Mesh3d(meshes.add(Sphere { radius: 1. })),
+        MeshMaterial3d(materials.add(Color::srgba(0.6, 0.1, 0.8, 1.))),
+        Transform::from_xyz(-8., 1., -4.),
+        Health {
+            max: 100.,
+            current: 70.,
+        },
+        BarSettings::<Health> {
+            offset: 1.5,
+            width: 2.,
+            height: BarHeight::Static(0.25),
+            occlusion: BarOcclusion::AlwaysOnTop,
+            edge_clamp: Some(BarEdgeClamp::new(24.).arrow(ORANGE.into())),
+            ..default()
+        },
+    ));
+
+    // Objective, clamped without an arrow
+    commands.spawn((
+        Mesh3d(meshes.add(Cuboid::new(1., 1., 1.))),
+        MeshMaterial3d(materials.add(Color::srgba(0.9, 0.8, 0.2, 1.))),
+        Transform::from_xyz(8., 0.5, 4.),
+        Health {
+            max: 10.,
+            current: 4.,
+        },
+        BarSettings::<Health> {
+            offset: 1.,
+            width: 1.5,
+            edge_clamp: Some(BarEdgeClamp::default()),
+            ..default()
+        },
+    ));
+
+    // Light
+    commands.spawn((
+        PointLight {
+            intensity: 1500000.0,
+            shadows_enabled: true,
+            range: 40.,
+            ..Default::default()
+        },
+        Transform::from_xyz(0.0, 10.0, 0.0),
+    ));
+
+    // Player camera, turning around so that the targets leave the screen
+    commands.spawn((
+        Camera3d::default(),
+        Msaa::Sample4,
+        Transform::from_xyz(0., 2., 0.),
+        PlayerCamera,
+    ));
+
+    // Minimap camera, bars are not clamped to its edges
+    commands.spawn((
+        Camera3d::default(),
+        Camera {
+            order: 1,
+            viewport: Some(Viewport {
+                physical_position: UVec2::new(16, 16),
+                physical_size: UVec2::new(256, 256),
+                ..default()
+            }),
+            ..default()
+        },
+        Msaa::Sample4,
+        Transform::from_xyz(0., 25., 0.).looking_at(Vec3::ZERO, Vec3::NEG_Z),
+        HealthBarCameraSettings {
+            edge_clamp: false,
+            ..default()
+        },
+    ));
+}
+
+fn look_around(time: Res<Time>, mut query: Query<&mut Transform, With<PlayerCamera>>) {
+    query.iter_mut().for_each(|mut transform| {
+        transform.rotation = Quat::from_rotation_y(0.4 * time.elapsed_secs());
+    });
+}
@@ -0,0 +1,129 @@
+use bevy::asset::RenderAssetUsages;
+use bevy::pbr::*;
+use bevy::prelude::*;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+use bevy_inspector_egui::bevy_egui::EguiPlugin;
+use bevy_inspector_egui::quick::WorldInspectorPlugin;
+
+use bevy_health_bar3d::prelude::{
+    BarHeight, BarIcon, BarIconPlacement, BarSettings, HealthBarPlugin, Percentage,
+};
+
+#[derive(Component, Reflect)]
+struct Health {
+    max: f32,
+    current: f32,
+}
+
+impl Percentage for Health {
+    fn value(&self) -> f32 {
+        self.current / self.max
+    }
+}
+
+fn main() {
+    App::new()
+        .register_type::<Health>()
+        .add_plugins((
+            DefaultPlugins,
+            EguiPlugin::default(),
+            WorldInspectorPlugin::new(),
+            HealthBarPlugin::<Health>::default(),
+        ))
+        .add_systems(Startup, setup)
+        .run();
+}
+
+/// Creates a small diamond shaped icon, in a game this would be loaded from an image file instead
+fn diamond_icon(color: [u8; 4]) -> Image {
+    const SIZE: u32 = 16;
+    let half = SIZE as i32 / 2;
+
+    let data = (0..SIZE * SIZE)
+        .flat_map(|i| {
+            let (x, y) = ((i % SIZE) as i32, (i / SIZE) as i32);
+            let inside = (x - half).abs() + (y - half).abs() < half;
+            if inside {
+                color
+            } else {
+                [0, 0, 0, 0]
+            }
+        })
+        .collect();
+
+    Image::new(
+        Extent3d {
+            width: SIZE,
+            height: SIZE,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    )
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut images: ResMut<Assets<Image>>,
+) {
+    let radius = 0.2;
+    let bar_height = 0.08;
+
+    let icons = [
+        (
+            images.add(diamond_icon([255, 215, 0, 255])),
+            BarIconPlacement::Left,
+        ),
+        (
+            images.add(diamond_icon([80, 160, 255, 255])),
+            BarIconPlacement::Right,
+        ),
+    ];
+
+    icons
+        .into_iter()
+        .enumerate()
+        .for_each(|(i, (image, placement))| {
+            commands.spawn((
+                Mesh3d(meshes.add(Sphere { radius })),
+                MeshMaterial3d(materials.add(Color::srgba(1., 0.2, 0.2, 1.))),
+                Transform::from_xyz(-0.5 + i as f32, 1., 0.),
+                Health {
+                    max: 10.,
+                    current: 4. + 4. * i as f32,
+                },
+                BarSettings::<Health> {
+                    offset: radius * 1.5,
+                    width: radius * 2.,
+                    height: BarHeight::Static(bar_height),
+                    icon: Some(
+                        BarIcon::new(image, bar_height * 2.)
+                            .placement(placement)
+                            .margin(0.02),
+                    ),
+                    ..default()
+                },
+            ));
+        });
+
+    // Light
+    commands.spawn((
+        PointLight {
+            intensity: 1500.0,
+            shadows_enabled: true,
+            ..Default::default()
+        },
+        Transform::from_xyz(4.0, 8.0, 4.0),
+    ));
+
+    // Camera
+    commands.spawn((
+        Camera3d::default(),
+        Msaa::Sample4,
+        Transform::from_xyz(0., 1.5, 4.0).looking_at(Vec3::Y, Vec3::Y),
+    ));
+}
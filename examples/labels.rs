@@ -0,0 +1,77 @@
+use bevy::pbr::*;
+use bevy::prelude::*;
+
+use bevy_health_bar3d::prelude::{
+    BarHeight, BarLabel, BarLabelContent, BarLabelPlacement, BarSettings, HealthBarPlugin, Stat,
+};
+
+#[derive(TypePath)]
+struct HealthStat;
+
+type Health = Stat<HealthStat>;
+
+fn main() {
+    App::new()
+        .register_type::<Health>()
+        .add_plugins((DefaultPlugins, HealthBarPlugin::<Health>::default()))
+        .add_systems(Startup, setup)
+        .add_systems(Update, update_health)
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let radius = 0.2;
+    let labels = [
+        BarLabel::new(BarLabelContent::Value).font_size(12.),
+        BarLabel::new(BarLabelContent::Static("Goblin Lv.3".into()))
+            .placement(BarLabelPlacement::Above),
+        BarLabel::new(BarLabelContent::Value).placement(BarLabelPlacement::Beside),
+    ];
+
+    labels.into_iter().enumerate().for_each(|(i, label)| {
+        commands.spawn((
+            Mesh3d(meshes.add(Sphere { radius })),
+            MeshMaterial3d(materials.add(Color::srgba(1., 0.2, 0.2, 1.))),
+            Transform::from_xyz(-1. + i as f32, 1., 0.),
+            Health::new(100. - 25. * i as f32, 100.),
+            BarSettings::<Health> {
+                offset: radius * 1.5,
+                width: radius * 3.,
+                height: BarHeight::Static(0.12),
+                label: Some(label),
+                ..default()
+            },
+        ));
+    });
+
+    // Light
+    commands.spawn((
+        PointLight {
+            intensity: 1500.0,
+            shadows_enabled: true,
+            ..Default::default()
+        },
+        Transform::from_xyz(4.0, 8.0, 4.0),
+    ));
+
+    // Camera
+    commands.spawn((
+        Camera3d::default(),
+        Msaa::Sample4,
+        Transform::from_xyz(0., 1.5, 4.0).looking_at(Vec3::Y, Vec3::Y),
+    ));
+}
+
+fn update_health(time: Res<Time>, mut query: Query<&mut Health>) {
+    query.iter_mut().for_each(|mut health| {
+        health.current -= 10. * time.delta_secs();
+
+        if health.current < 0. {
+            health.current = health.max
+        }
+    })
+}
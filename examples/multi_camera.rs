@@ -1,3 +1,5 @@
+use bevy::camera::visibility::RenderLayers;
+use bevy::camera::Viewport;
 use bevy::pbr::*;
 use bevy::prelude::*;
 use bevy_health_bar3d::prelude::{BarSettings, HealthBarPlugin, Percentage};
@@ -35,10 +37,14 @@ fn setup(
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
 ) {
+    // Everything is rendered by both the gameplay camera (layer 0) and the minimap camera (layer 1)
+    let all_layers = RenderLayers::from_layers(&[0, 1]);
+
     // Ground
     commands.spawn((
         Mesh3d(meshes.add(Plane3d::default().mesh().size(5.0, 5.0))),
         MeshMaterial3d(materials.add(Color::srgba(0.3, 0.5, 0.3, 1.))),
+        all_layers.clone(),
     ));
 
     let radius = 0.2;
@@ -47,13 +53,16 @@ fn setup(
         Mesh3d(meshes.add(Sphere { radius })),
         MeshMaterial3d(materials.add(Color::srgba(1., 0.2, 0.2, 1.))),
         Transform::from_xyz(0.0, 1., 0.0),
+        all_layers.clone(),
         Health {
             max: 10.,
             current: 8.,
         },
+        // Only visible in the gameplay camera
         BarSettings::<Health> {
             offset: radius * 1.5,
             width: radius * 2.,
+            render_layers: Some(RenderLayers::layer(0)),
             ..default()
         },
     ));
@@ -62,10 +71,12 @@ fn setup(
         Mesh3d(meshes.add(Sphere { radius })),
         MeshMaterial3d(materials.add(Color::srgba(1., 0.2, 0.2, 1.))),
         Transform::from_xyz(0.0 + 3. * radius, 0.5, 0.0),
+        all_layers,
         Health {
             max: 10.,
             current: 2.,
         },
+        // Inherits the render layers of the entity, and is visible in both cameras
         BarSettings::<Health> {
             offset: radius * 1.5,
             width: radius * 2.,
@@ -81,6 +92,7 @@ fn setup(
             ..Default::default()
         },
         Transform::from_xyz(4.0, 8.0, 4.0),
+        RenderLayers::from_layers(&[0, 1]),
     ));
 
     // Rotating Camera
@@ -91,16 +103,21 @@ fn setup(
         Rotate,
     ));
 
-    // Static Camera
+    // Minimap Camera
     commands.spawn((
         Camera3d::default(),
         Camera {
-            clear_color: ClearColorConfig::None,
             order: 1,
+            viewport: Some(Viewport {
+                physical_position: UVec2::new(16, 16),
+                physical_size: UVec2::new(256, 256),
+                ..default()
+            }),
             ..default()
         },
         Msaa::Sample4,
-        Transform::from_xyz(1., 2., 5.),
+        Transform::from_xyz(0., 6., 0.).looking_at(Vec3::ZERO, Vec3::NEG_Z),
+        RenderLayers::layer(1),
     ));
 }
 
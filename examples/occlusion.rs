@@ -0,0 +1,98 @@
+use bevy::core_pipeline::prepass::DepthPrepass;
+use bevy::pbr::*;
+use bevy::prelude::*;
+
+use bevy_health_bar3d::prelude::{BarOcclusion, BarSettings, HealthBarPlugin, Percentage};
+
+#[derive(Component, Reflect)]
+struct Health {
+    max: f32,
+    current: f32,
+}
+
+impl Percentage for Health {
+    fn value(&self) -> f32 {
+        self.current / self.max
+    }
+}
+
+#[derive(Component)]
+struct Wall;
+
+fn main() {
+    App::new()
+        .register_type::<Health>()
+        .add_plugins((DefaultPlugins, HealthBarPlugin::<Health>::default()))
+        .add_systems(Startup, setup)
+        .add_systems(Update, move_wall)
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // Ground
+    commands.spawn((
+        Mesh3d(meshes.add(Plane3d::default().mesh().size(8.0, 8.0))),
+        MeshMaterial3d(materials.add(Color::srgba(0.3, 0.5, 0.3, 1.))),
+    ));
+
+    // Wall moving in front of the units
+    commands.spawn((
+        Mesh3d(meshes.add(Cuboid::new(1.5, 1.5, 0.1))),
+        MeshMaterial3d(materials.add(Color::srgba(0.6, 0.6, 0.6, 1.))),
+        Transform::from_xyz(0., 0.75, 1.),
+        Wall,
+    ));
+
+    let radius = 0.3;
+    let modes = [
+        BarOcclusion::DepthTested,
+        BarOcclusion::AlwaysOnTop,
+        BarOcclusion::XRay,
+    ];
+
+    modes.into_iter().enumerate().for_each(|(i, occlusion)| {
+        commands.spawn((
+            Mesh3d(meshes.add(Sphere { radius })),
+            MeshMaterial3d(materials.add(Color::srgba(1., 0.2, 0.2, 1.))),
+            Transform::from_xyz(-1.5 + 1.5 * i as f32, radius, 0.),
+            Health {
+                max: 10.,
+                current: 7.,
+            },
+            BarSettings::<Health> {
+                offset: radius * 1.5,
+                width: radius * 2.,
+                occlusion,
+                ..default()
+            },
+        ));
+    });
+
+    // Light
+    commands.spawn((
+        PointLight {
+            intensity: 1500.0,
+            shadows_enabled: true,
+            ..Default::default()
+        },
+        Transform::from_xyz(4.0, 8.0, 4.0),
+    ));
+
+    // Camera, the depth prepass is required for x-ray bars to dim their occluded parts
+    commands.spawn((
+        Camera3d::default(),
+        Msaa::Sample4,
+        DepthPrepass,
+        Transform::from_xyz(0., 1., 4.).looking_at(Vec3::new(0., 0.3, 0.), Vec3::Y),
+    ));
+}
+
+fn move_wall(time: Res<Time>, mut query: Query<&mut Transform, With<Wall>>) {
+    query.iter_mut().for_each(|mut transform| {
+        transform.translation.x = 2.5 * ops::sin(0.5 * time.elapsed_secs());
+    });
+}
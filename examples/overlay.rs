@@ -0,0 +1,125 @@
+use bevy::color::palettes::css::*;
+use bevy::pbr::*;
+use bevy::prelude::*;
+
+use bevy_health_bar3d::prelude::{
+    BarBorder, BarHeight, BarOrientation, BarRenderMode, BarSettings, HealthBarPlugin, Percentage,
+};
+
+#[derive(Component, Reflect)]
+struct Health {
+    max: f32,
+    current: f32,
+}
+
+impl Percentage for Health {
+    fn value(&self) -> f32 {
+        self.current / self.max
+    }
+}
+
+fn main() {
+    App::new()
+        .register_type::<Health>()
+        .add_plugins((DefaultPlugins, HealthBarPlugin::<Health>::default()))
+        .add_systems(Startup, setup)
+        .add_systems(Update, (orbit_camera, damage))
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // Ground
+    commands.spawn((
+        Mesh3d(meshes.add(Plane3d::default().mesh().size(20.0, 20.0))),
+        MeshMaterial3d(materials.add(Color::srgba(0.3, 0.5, 0.3, 1.))),
+    ));
+
+    // Wall, overlay bars are drawn on top of it without any depth interaction
+    commands.spawn((
+        Mesh3d(meshes.add(Cuboid::new(6., 3., 0.5))),
+        MeshMaterial3d(materials.add(Color::srgba(0.5, 0.5, 0.55, 1.))),
+        Transform::from_xyz(0., 1.5, 2.),
+    ));
+
+    // Horizontal overlay bars, sized in pixels regardless of their distance to the camera
+    [(-3., RED), (0., BLUE), (3., PURPLE)]
+        .into_iter()
+        .for_each(|(x, color)| {
+            commands.spawn((
+                Mesh3d(meshes.add(Sphere { radius: 1. })),
+                MeshMaterial3d(materials.add(Color::from(color))),
+                Transform::from_xyz(x, 1., -2.),
+                Health {
+                    max: 10.,
+                    current: 10.,
+                },
+                BarSettings::<Health> {
+                    offset: 1.5,
+                    width: 80.,
+                    height: BarHeight::Static(10.),
+                    border: BarBorder::new(2.),
+                    render_mode: BarRenderMode::ScreenOverlay { camera: None },
+                    ..default()
+                },
+            ));
+        });
+
+    // Vertical overlay bar
+    commands.spawn((
+        Mesh3d(meshes.add(Cuboid::new(1., 2., 1.))),
+        MeshMaterial3d(materials.add(Color::from(ORANGE))),
+        Transform::from_xyz(0., 1., -6.),
+        Health {
+            max: 10.,
+            current: 10.,
+        },
+        BarSettings::<Health> {
+            offset: 1.,
+            width: 60.,
+            height: BarHeight::Static(8.),
+            orientation: BarOrientation::Vertical,
+            render_mode: BarRenderMode::ScreenOverlay { camera: None },
+            ..default()
+        },
+    ));
+
+    // Light
+    commands.spawn((
+        PointLight {
+            intensity: 1500000.0,
+            shadows_enabled: true,
+            ..Default::default()
+        },
+        Transform::from_xyz(4.0, 8.0, 4.0),
+    ));
+
+    // Camera
+    commands.spawn((
+        Camera3d::default(),
+        Msaa::Sample4,
+        Transform::from_xyz(0., 6., 12.).looking_at(Vec3::new(0., 1., -3.), Vec3::Y),
+    ));
+}
+
+fn orbit_camera(time: Res<Time>, mut query: Query<&mut Transform, With<Camera>>) {
+    query.iter_mut().for_each(|mut transform| {
+        let angle = 0.5 * time.elapsed_secs().sin();
+        let position = Quat::from_rotation_y(angle) * Vec3::new(0., 6., 12.);
+        *transform =
+            Transform::from_translation(position).looking_at(Vec3::new(0., 1., -3.), Vec3::Y);
+    });
+}
+
+fn damage(time: Res<Time>, mut query: Query<&mut Health>) {
+    query
+        .iter_mut()
+        .enumerate()
+        .for_each(|(index, mut health)| {
+            let phase = time.elapsed_secs() * 0.5 + index as f32;
+            health.current = health.max * (0.5 + 0.5 * phase.sin());
+        });
+}
@@ -0,0 +1,119 @@
+use bevy::pbr::*;
+use bevy::prelude::*;
+
+use bevy_health_bar3d::prelude::{
+    BarBorder, BarHeight, BarOcclusion, BarSettings, BarStyle, BarStyles, ColorScheme,
+    ForegroundColor, HealthBarPlugin, Stat,
+};
+
+#[derive(TypePath)]
+struct HealthStat;
+
+#[derive(TypePath)]
+struct ShieldStat;
+
+type Health = Stat<HealthStat>;
+type Shield = Stat<ShieldStat>;
+
+fn main() {
+    App::new()
+        .register_type::<Health>()
+        .register_type::<Shield>()
+        .add_plugins((
+            DefaultPlugins,
+            HealthBarPlugin::<Health>::default(),
+            HealthBarPlugin::<Shield>::default(),
+        ))
+        .insert_resource(
+            ColorScheme::<Shield>::new()
+                .foreground_color(ForegroundColor::Static(Color::srgb(0.2, 0.7, 1.))),
+        )
+        .add_systems(Startup, (register_presets, setup).chain())
+        .add_systems(Update, (drain::<HealthStat>, drain::<ShieldStat>))
+        .run();
+}
+
+/// Defines the presets once, to be shared by the bars of all tracked component types
+fn register_presets(mut styles: ResMut<Assets<BarStyle>>, mut presets: ResMut<BarStyles>) {
+    presets.insert(
+        "elite",
+        styles.add(BarStyle {
+            width: Some(0.8),
+            height: Some(BarHeight::Static(0.08)),
+            border: Some(BarBorder::new(0.01).color(Color::srgb(0.75, 0.75, 0.8))),
+            ..default()
+        }),
+    );
+
+    presets.insert(
+        "boss",
+        styles.add(BarStyle {
+            width: Some(1.4),
+            height: Some(BarHeight::Static(0.12)),
+            border: Some(BarBorder::new(0.02).color(Color::srgb(1., 0.8, 0.))),
+            background_color: Some(Color::srgba(0.1, 0., 0.15, 0.75)),
+            occlusion: Some(BarOcclusion::AlwaysOnTop),
+            ..default()
+        }),
+    );
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let units = [("elite", 0.25, -1.2), ("boss", 0.45, 0.8)];
+
+    units.into_iter().for_each(|(preset, radius, x)| {
+        commands.spawn((
+            Mesh3d(meshes.add(Sphere { radius })),
+            MeshMaterial3d(materials.add(Color::srgba(1., 0.2, 0.2, 1.))),
+            Transform::from_xyz(x, 1., 0.),
+            Health::full(100.),
+            BarSettings::<Health> {
+                offset: radius * 1.5,
+                preset: Some(preset.into()),
+                ..default()
+            },
+            Shield::full(50.),
+            BarSettings::<Shield> {
+                offset: radius * 1.5 + 0.15,
+                preset: Some(preset.into()),
+                // the shield bar shares the preset, but is slimmer
+                overrides: BarStyle {
+                    height: Some(BarHeight::Static(0.05)),
+                    ..default()
+                },
+                ..default()
+            },
+        ));
+    });
+
+    // Light
+    commands.spawn((
+        PointLight {
+            intensity: 1500.0,
+            shadows_enabled: true,
+            ..Default::default()
+        },
+        Transform::from_xyz(4.0, 8.0, 4.0),
+    ));
+
+    // Camera
+    commands.spawn((
+        Camera3d::default(),
+        Msaa::Sample4,
+        Transform::from_xyz(0., 1.5, 4.0).looking_at(Vec3::Y, Vec3::Y),
+    ));
+}
+
+fn drain<M: TypePath + Send + Sync>(time: Res<Time>, mut query: Query<&mut Stat<M>>) {
+    query.iter_mut().for_each(|mut stat| {
+        stat.current -= stat.max / 8. * time.delta_secs();
+
+        if stat.current < 0. {
+            stat.current = stat.max
+        }
+    })
+}
@@ -0,0 +1,102 @@
+use bevy::pbr::*;
+use bevy::prelude::*;
+
+use bevy_health_bar3d::prelude::{BarSettings, BarStyle, HealthBarPlugin, Percentage};
+
+#[derive(Component, Reflect)]
+struct Health {
+    max: f32,
+    current: f32,
+}
+
+impl Percentage for Health {
+    fn value(&self) -> f32 {
+        self.current / self.max
+    }
+}
+
+fn main() {
+    App::new()
+        .register_type::<Health>()
+        .add_plugins((DefaultPlugins, HealthBarPlugin::<Health>::default()))
+        .add_systems(Startup, setup)
+        .add_systems(Update, update_health)
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    asset_server: Res<AssetServer>,
+) {
+    // Edit the style files while the example is running, with bevy's `file_watcher` feature enabled,
+    // to see the changes applied to every bar using the style
+    let boss_style: Handle<BarStyle> = asset_server.load("styles/boss.bar.ron");
+    let minion_style: Handle<BarStyle> = asset_server.load("styles/minion.bar.ron");
+
+    let boss_radius = 0.4;
+    commands.spawn((
+        Mesh3d(meshes.add(Sphere {
+            radius: boss_radius,
+        })),
+        MeshMaterial3d(materials.add(Color::srgba(0.5, 0.1, 0.6, 1.))),
+        Transform::from_xyz(0., 1., 0.),
+        Health {
+            max: 100.,
+            current: 100.,
+        },
+        BarSettings::<Health> {
+            offset: boss_radius * 1.5,
+            style: Some(boss_style),
+            ..default()
+        },
+    ));
+
+    let minion_radius = 0.15;
+    (0..4).for_each(|i| {
+        commands.spawn((
+            Mesh3d(meshes.add(Sphere {
+                radius: minion_radius,
+            })),
+            MeshMaterial3d(materials.add(Color::srgba(0.3, 0.6, 0.3, 1.))),
+            Transform::from_xyz(-1.5 + i as f32, 0.3, 1.),
+            Health {
+                max: 10.,
+                current: 10. - 2. * i as f32,
+            },
+            BarSettings::<Health> {
+                offset: minion_radius * 1.5,
+                style: Some(minion_style.clone()),
+                ..default()
+            },
+        ));
+    });
+
+    // Light
+    commands.spawn((
+        PointLight {
+            intensity: 1500.0,
+            shadows_enabled: true,
+            ..Default::default()
+        },
+        Transform::from_xyz(4.0, 8.0, 4.0),
+    ));
+
+    // Camera
+    commands.spawn((
+        Camera3d::default(),
+        Msaa::Sample4,
+        Transform::from_xyz(0., 2., 5.0).looking_at(Vec3::new(0., 0.8, 0.), Vec3::Y),
+    ));
+}
+
+fn update_health(time: Res<Time>, mut query: Query<&mut Health>) {
+    query.iter_mut().for_each(|mut health| {
+        health.current -= health.max / 10. * time.delta_secs();
+
+        if health.current < 0. {
+            health.current = health.max
+        }
+    })
+}
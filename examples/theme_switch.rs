@@ -0,0 +1,120 @@
+use bevy::pbr::*;
+use bevy::prelude::*;
+
+use bevy_health_bar3d::prelude::{
+    BarSettings, ColorScheme, ForegroundColor, HealthBarPlugin, Percentage,
+};
+
+#[derive(Component, Reflect)]
+struct Health {
+    max: f32,
+    current: f32,
+}
+
+impl Percentage for Health {
+    fn value(&self) -> f32 {
+        self.current / self.max
+    }
+}
+
+fn main() {
+    App::new()
+        .register_type::<Health>()
+        .add_plugins((DefaultPlugins, HealthBarPlugin::<Health>::default()))
+        .add_systems(Startup, setup)
+        .add_systems(Update, switch_theme)
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let radius = 0.2;
+
+    (0..3).for_each(|i| {
+        commands.spawn((
+            Mesh3d(meshes.add(Sphere { radius })),
+            MeshMaterial3d(materials.add(Color::srgba(1., 0.2, 0.2, 1.))),
+            Transform::from_xyz(-1.5 + i as f32, 1., 0.),
+            Health {
+                max: 10.,
+                current: 9. - 3. * i as f32,
+            },
+            BarSettings::<Health> {
+                offset: radius * 1.5,
+                width: radius * 2.,
+                ..default()
+            },
+        ));
+    });
+
+    // The player's bar overrides its colors and is not affected by theme switches
+    commands.spawn((
+        Mesh3d(meshes.add(Sphere { radius })),
+        MeshMaterial3d(materials.add(Color::srgba(0.2, 0.4, 1., 1.))),
+        Transform::from_xyz(1.5, 1., 0.),
+        Health {
+            max: 10.,
+            current: 7.,
+        },
+        BarSettings::<Health> {
+            offset: radius * 1.5,
+            width: radius * 2.,
+            foreground_color: Some(ForegroundColor::Static(Color::srgb(0.2, 0.6, 1.0))),
+            background_color: Some(Color::srgb(0.05, 0.05, 0.2)),
+            ..default()
+        },
+    ));
+
+    commands.spawn((
+        Text::new("Press space to switch the color scheme"),
+        Node {
+            position_type: PositionType::Absolute,
+            top: px(12),
+            left: px(12),
+            ..default()
+        },
+    ));
+
+    // Light
+    commands.spawn((
+        PointLight {
+            intensity: 1500.0,
+            shadows_enabled: true,
+            ..Default::default()
+        },
+        Transform::from_xyz(4.0, 8.0, 4.0),
+    ));
+
+    // Camera
+    commands.spawn((
+        Camera3d::default(),
+        Msaa::Sample4,
+        Transform::from_xyz(0., 1.5, 4.0).looking_at(Vec3::Y, Vec3::Y),
+    ));
+}
+
+fn switch_theme(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut color_scheme: ResMut<ColorScheme<Health>>,
+    mut dark: Local<bool>,
+) {
+    if !keyboard_input.just_pressed(KeyCode::Space) {
+        return;
+    }
+
+    *dark = !*dark;
+    *color_scheme = if *dark {
+        ColorScheme::new()
+            .foreground_color(ForegroundColor::TriSpectrum {
+                high: Color::srgb(0.6, 0.4, 1.),
+                moderate: Color::srgb(1., 0.5, 0.8),
+                low: Color::srgb(1., 0.3, 0.3),
+            })
+            .background_color(Color::srgba(0.05, 0.05, 0.05, 0.9))
+    } else {
+        ColorScheme::new()
+    };
+}
@@ -0,0 +1,62 @@
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+
+use crate::configuration::{BarAnchor, BarSettings, Percentage};
+use crate::plugin::BarTransform;
+use crate::relationship::HasBar;
+
+/// Returns the first descendant of the owner with the given name, skipping its bars
+fn find_named_descendant(
+    owner: Entity,
+    name: &str,
+    children_query: &Query<&Children>,
+    name_query: &Query<&Name>,
+    bar_query: &Query<&mut BarTransform>,
+) -> Option<Entity> {
+    let mut queue = VecDeque::from([owner]);
+    while let Some(entity) = queue.pop_front() {
+        if entity != owner && name_query.get(entity).is_ok_and(|n| n.as_str() == name) {
+            return Some(entity);
+        }
+
+        if let Ok(children) = children_query.get(entity) {
+            queue.extend(children.iter().filter(|child| !bar_query.contains(*child)));
+        }
+    }
+
+    None
+}
+
+/// Resolves [`BarAnchor::Name`] anchors. Runs every frame, as the descendants of glTF scenes are spawned after
+/// their root and may be replaced.
+pub(crate) fn update_anchors<T: Percentage + Component + TypePath>(
+    owner_query: Query<(Entity, &HasBar<T>, &BarSettings<T>)>,
+    mut bar_query: Query<&mut BarTransform>,
+    children_query: Query<&Children>,
+    name_query: Query<&Name>,
+) {
+    owner_query.iter().for_each(|(owner, has_bar, settings)| {
+        let Some(BarAnchor::Name(name)) = &settings.anchor else {
+            return;
+        };
+        let Ok(bar_transform) = bar_query.get(has_bar.bar()) else {
+            return;
+        };
+
+        let resolved = bar_transform
+            .anchor
+            .and_then(|anchor| name_query.get(anchor).ok())
+            .is_some_and(|anchor_name| anchor_name.as_str() == name);
+        if resolved {
+            return;
+        }
+
+        let anchor = find_named_descendant(owner, name, &children_query, &name_query, &bar_query);
+        if let Ok(mut bar_transform) = bar_query.get_mut(has_bar.bar()) {
+            if bar_transform.anchor != anchor {
+                bar_transform.anchor = anchor;
+            }
+        }
+    });
+}
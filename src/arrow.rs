@@ -0,0 +1,149 @@
+use bevy::camera::visibility::{NoFrustumCulling, RenderLayers};
+use bevy::light::{NotShadowCaster, NotShadowReceiver};
+use bevy::prelude::*;
+
+use crate::configuration::Percentage;
+use crate::material::BarMaterial;
+use crate::mesh::MeshHandles;
+use crate::plugin::SettingsChanged;
+use crate::prelude::{BarOffsetMode, BarSettings};
+use crate::relationship::{Attached, HasAttached, HasBar};
+use crate::style::StyleResolver;
+
+/// Marks entities attached to a bar as its arrow
+pub(crate) struct ArrowAttachment;
+
+pub(crate) type ArrowOf = Attached<ArrowAttachment>;
+pub(crate) type HasArrow = HasAttached<ArrowAttachment>;
+
+/// Returns the mesh of an arrow pointing along the x-axis. It is rotated towards the entity in the vertex shader.
+fn arrow_mesh<T: Percentage + Component + TypePath>(
+    settings: &BarSettings<T>,
+    meshes: &mut Assets<Mesh>,
+    mesh_handles: &mut MeshHandles,
+) -> Handle<Mesh> {
+    let size = settings.arrow_size();
+
+    mesh_handles.get_arrow(size).unwrap_or_else(|| {
+        let half_size = size / 2.;
+        let handle = meshes.add(Triangle2d::new(
+            Vec2::new(half_size, 0.),
+            Vec2::new(-half_size, half_size),
+            Vec2::new(-half_size, -half_size),
+        ));
+        mesh_handles.insert_arrow(size, handle.clone());
+        handle
+    })
+}
+
+/// Returns the color of the arrow of the bar, if it has one
+fn arrow_color<T: Percentage + Component + TypePath>(settings: &BarSettings<T>) -> Option<Color> {
+    settings
+        .edge_clamp
+        .as_ref()
+        .and_then(|edge_clamp| edge_clamp.arrow)
+}
+
+fn apply_arrow<T: Percentage + Component + TypePath>(
+    material: &mut BarMaterial,
+    color: Color,
+    settings: &BarSettings<T>,
+) {
+    material.value_and_dimensions = Vec4::new(1., settings.arrow_size(), settings.arrow_size(), 0.);
+    material.high_color = color.into();
+    material.offset = settings.normalized_offset().extend(0.);
+    material.world_space_offset = settings.offset_mode == BarOffsetMode::WorldSpace;
+    material.occlusion = settings.occlusion;
+    material.depth_bias = settings.depth_bias;
+    material.edge_clamp = settings.edge_clamp_parameters();
+    material.clamp_to_edge = true;
+    material.arrow = true;
+}
+
+pub(crate) fn spawn_arrow<T: Percentage + Component + TypePath>(
+    commands: &mut Commands,
+    materials: &mut Assets<BarMaterial>,
+    meshes: &mut Assets<Mesh>,
+    mesh_handles: &mut MeshHandles,
+    bar: Entity,
+    settings: &BarSettings<T>,
+    render_layers: Option<&RenderLayers>,
+) {
+    let Some(color) = arrow_color(settings) else {
+        return;
+    };
+
+    let mut material = BarMaterial::default();
+    apply_arrow(&mut material, color, settings);
+
+    let mut arrow = commands.spawn((
+        Name::new(format!("{}Arrow", T::type_path())),
+        Mesh3d(arrow_mesh(settings, meshes, mesh_handles)),
+        MeshMaterial3d(materials.add(material)),
+        NotShadowCaster,
+        NotShadowReceiver,
+        NoFrustumCulling,
+        ArrowOf::new(bar),
+        ChildOf(bar),
+    ));
+
+    if let Some(render_layers) = render_layers {
+        arrow.insert(render_layers.clone());
+    }
+}
+
+/// Spawns, updates and despawns the arrows of edge clamped bars according to their settings
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+pub(crate) fn update_arrow_settings<T: Percentage + Component + TypePath>(
+    mut commands: Commands,
+    mut materials: ResMut<Assets<BarMaterial>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut mesh_handles: ResMut<MeshHandles>,
+    styles: StyleResolver,
+    parent_query: Query<(&HasBar<T>, &BarSettings<T>, Option<&RenderLayers>), SettingsChanged<T>>,
+    bar_query: Query<Option<&HasArrow>>,
+    arrow_query: Query<&MeshMaterial3d<BarMaterial>>,
+) {
+    parent_query
+        .iter()
+        .for_each(|(bar, settings, owner_layers)| {
+            let Ok(has_arrow) = bar_query.get(bar.bar()) else {
+                return;
+            };
+            let settings = styles.resolve(settings);
+            let render_layers = settings.render_layers(owner_layers);
+
+            match (has_arrow, arrow_color(&settings)) {
+                (Some(has_arrow), Some(color)) => {
+                    let Ok(material_handle) = arrow_query.get(has_arrow.get()) else {
+                        return;
+                    };
+
+                    let material = materials.get_mut(&material_handle.0).unwrap();
+                    apply_arrow(material, color, &settings);
+
+                    let mut arrow = commands.entity(has_arrow.get());
+                    arrow.insert(Mesh3d(arrow_mesh(
+                        &settings,
+                        &mut meshes,
+                        &mut mesh_handles,
+                    )));
+                    match render_layers {
+                        Some(render_layers) => arrow.insert(render_layers),
+                        None => arrow.remove::<RenderLayers>(),
+                    };
+                }
+                (Some(has_arrow), None) => commands.entity(has_arrow.get()).despawn(),
+                (None, Some(_)) => spawn_arrow(
+                    &mut commands,
+                    &mut materials,
+                    &mut meshes,
+                    &mut mesh_handles,
+                    bar.bar(),
+                    &settings,
+                    render_layers.as_ref(),
+                ),
+                (None, None) => {}
+            }
+        });
+}
@@ -0,0 +1,235 @@
+use bevy::asset::RenderAssetUsages;
+use bevy::prelude::*;
+use bevy::render::extract_resource::ExtractResource;
+use bevy::render::render_asset::RenderAssets;
+use bevy::render::render_resource::{
+    Extent3d, TexelCopyBufferLayout, TextureDimension, TextureFormat, TextureId,
+};
+use bevy::render::renderer::RenderQueue;
+use bevy::render::texture::GpuImage;
+
+use crate::configuration::BarBillboardMode;
+use crate::constants::BAR_CAMERAS_TEXTURE_HANDLE;
+
+/// Maximum number of cameras with [`HealthBarCameraSettings`]. Further cameras are ignored.
+pub const MAX_BAR_CAMERAS: usize = 4;
+
+const CAMERA_ACTIVE: u32 = 1;
+const CAMERA_HIDDEN: u32 = 1 << 1;
+const CAMERA_BILLBOARD: u32 = 1 << 2;
+const CAMERA_BILLBOARD_SHIFT: u32 = 3;
+const CAMERA_NO_EDGE_CLAMP: u32 = 1 << 5;
+
+/// Size in bytes of a texel of the camera texture
+const TEXEL_SIZE: usize = 4 * size_of::<f32>();
+
+/// Camera component to customize how bars are rendered in the view of that camera.
+///
+/// Cameras are told apart in the shader by their viewport and projection, so cameras sharing both,
+/// such as two cameras layered on top of each other with identical projections, can't have different settings.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_bar3d::prelude::HealthBarCameraSettings;
+///
+/// fn spawn_strategic_camera(mut commands: Commands) {
+///     commands.spawn((
+///         Camera3d::default(),
+///         HealthBarCameraSettings {
+///             scale: 3.,
+///             ..default()
+///         },
+///     ));
+/// }
+/// ```
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component, Default)]
+pub struct HealthBarCameraSettings {
+    /// Scale factor applied to the size of bars in this view
+    pub scale: f32,
+    /// Hides all bars in this view
+    pub hidden: bool,
+    /// Overrides the billboard mode of bars in this view
+    pub billboard: Option<BarBillboardMode>,
+    /// Clamps bars with [`BarSettings::edge_clamp`](crate::prelude::BarSettings::edge_clamp) to the edges of this view.
+    /// Disable it for views such as minimaps.
+    pub edge_clamp: bool,
+}
+
+impl Default for HealthBarCameraSettings {
+    fn default() -> Self {
+        Self {
+            scale: 1.,
+            hidden: false,
+            billboard: None,
+            edge_clamp: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct BarCamera {
+    viewport: Vec4,
+    projection: Vec2,
+    scale: f32,
+    flags: u32,
+}
+
+impl BarCamera {
+    fn new(camera: &Camera, settings: &HealthBarCameraSettings) -> Option<Self> {
+        let viewport = camera.physical_viewport_rect()?;
+        let clip_from_view = camera.clip_from_view();
+
+        let mut flags = CAMERA_ACTIVE;
+        if settings.hidden {
+            flags |= CAMERA_HIDDEN;
+        }
+        if let Some(billboard) = &settings.billboard {
+            flags |= CAMERA_BILLBOARD | (billboard.index() << CAMERA_BILLBOARD_SHIFT);
+        }
+        if !settings.edge_clamp {
+            flags |= CAMERA_NO_EDGE_CLAMP;
+        }
+
+        Some(Self {
+            viewport: Vec4::new(
+                viewport.min.x as f32,
+                viewport.min.y as f32,
+                viewport.width() as f32,
+                viewport.height() as f32,
+            ),
+            projection: Vec2::new(clip_from_view.x_axis.x, clip_from_view.y_axis.y),
+            scale: settings.scale,
+            flags,
+        })
+    }
+}
+
+/// Settings of all cameras with [`HealthBarCameraSettings`], indexed by a slot that a camera keeps for as long as it is
+/// active and has settings
+#[derive(Resource, ExtractResource, Debug, Clone, Default, PartialEq)]
+pub(crate) struct BarCameras {
+    slots: [Option<Entity>; MAX_BAR_CAMERAS],
+    entries: [BarCamera; MAX_BAR_CAMERAS],
+}
+
+impl BarCameras {
+    /// Texel data of the camera texture. The first row holds the viewports, the second one the projections, scales
+    /// and flags. Flags are stored as floats, which represent them exactly.
+    fn texels(&self) -> Vec<u8> {
+        let viewports = self.entries.iter().map(|camera| camera.viewport);
+        let settings = self.entries.iter().map(|camera| {
+            Vec4::new(
+                camera.projection.x,
+                camera.projection.y,
+                camera.scale,
+                camera.flags as f32,
+            )
+        });
+
+        viewports
+            .chain(settings)
+            .flat_map(|texel| texel.to_array())
+            .flat_map(f32::to_le_bytes)
+            .collect()
+    }
+}
+
+/// Texture holding the settings of all cameras, shared by the materials of all bars. It only lives in the render
+/// world, where it is written in place, so that bind groups referencing it stay valid.
+pub(crate) fn camera_texture() -> Image {
+    Image::new_fill(
+        Extent3d {
+            width: MAX_BAR_CAMERAS as u32,
+            height: 2,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        &BarCameras::default().texels()[..TEXEL_SIZE],
+        TextureFormat::Rgba32Float,
+        RenderAssetUsages::RENDER_WORLD,
+    )
+}
+
+/// Updates the settings of cameras whenever a camera or its settings change
+#[allow(clippy::type_complexity)]
+pub(crate) fn update_cameras(
+    mut cameras: ResMut<BarCameras>,
+    changed_query: Query<
+        (),
+        (
+            With<HealthBarCameraSettings>,
+            Or<(
+                Changed<HealthBarCameraSettings>,
+                Changed<Camera>,
+                Changed<Projection>,
+            )>,
+        ),
+    >,
+    mut removed: RemovedComponents<HealthBarCameraSettings>,
+    camera_query: Query<(Entity, &Camera, &HealthBarCameraSettings)>,
+) {
+    let removed = removed.read().count() > 0;
+    if changed_query.is_empty() && !removed {
+        return;
+    }
+
+    let mut slots = cameras.slots;
+    slots.iter_mut().for_each(|slot| {
+        if slot.is_some_and(|entity| {
+            !camera_query
+                .get(entity)
+                .is_ok_and(|(_, camera, _)| camera.is_active)
+        }) {
+            *slot = None;
+        }
+    });
+
+    for (entity, camera, _) in &camera_query {
+        if !camera.is_active || slots.contains(&Some(entity)) {
+            continue;
+        }
+        if let Some(slot) = slots.iter_mut().find(|slot| slot.is_none()) {
+            *slot = Some(entity);
+        }
+    }
+
+    let entries = slots.map(|slot| {
+        slot.and_then(|entity| camera_query.get(entity).ok())
+            .and_then(|(_, camera, settings)| BarCamera::new(camera, settings))
+            .unwrap_or_default()
+    });
+
+    cameras.set_if_neq(BarCameras { slots, entries });
+}
+
+/// Writes the settings of cameras to the camera texture whenever they or the texture change
+pub(crate) fn write_camera_texture(
+    cameras: Res<BarCameras>,
+    images: Res<RenderAssets<GpuImage>>,
+    queue: Res<RenderQueue>,
+    mut written: Local<Option<TextureId>>,
+) {
+    let Some(image) = images.get(&BAR_CAMERAS_TEXTURE_HANDLE) else {
+        return;
+    };
+
+    let texture_id = image.texture.id();
+    if !cameras.is_changed() && *written == Some(texture_id) {
+        return;
+    }
+
+    queue.write_texture(
+        image.texture.as_image_copy(),
+        &cameras.texels(),
+        TexelCopyBufferLayout {
+            offset: 0,
+            bytes_per_row: Some((MAX_BAR_CAMERAS * TEXEL_SIZE) as u32),
+            rows_per_image: None,
+        },
+        image.size,
+    );
+    *written = Some(texture_id);
+}
@@ -1,14 +1,27 @@
 use std::marker::PhantomData;
 
+use bevy::camera::visibility::RenderLayers;
 use bevy::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 use crate::constants::{
-    DEFAULT_BACKGROUND_COLOR, DEFAULT_BORDER_COLOR, DEFAULT_HIGH_COLOR, DEFAULT_LOW_COLOR,
-    DEFAULT_MODERATE_COLOR, DEFAULT_RELATIVE_HEIGHT, DEFAULT_WIDTH,
+    DEFAULT_BACKGROUND_COLOR, DEFAULT_BORDER_COLOR, DEFAULT_EDGE_CLAMP_MARGIN, DEFAULT_HIGH_COLOR,
+    DEFAULT_LOW_COLOR, DEFAULT_MODERATE_COLOR, DEFAULT_RELATIVE_HEIGHT, DEFAULT_WIDTH,
+    DEUTERANOPIA_HIGH_COLOR, DEUTERANOPIA_LOW_COLOR, DEUTERANOPIA_MODERATE_COLOR,
+    HIGH_CONTRAST_BACKGROUND_COLOR, HIGH_CONTRAST_HIGH_COLOR, HIGH_CONTRAST_LOW_COLOR,
+    HIGH_CONTRAST_MODERATE_COLOR, PROTANOPIA_HIGH_COLOR, PROTANOPIA_LOW_COLOR,
+    PROTANOPIA_MODERATE_COLOR, TRITANOPIA_HIGH_COLOR, TRITANOPIA_LOW_COLOR,
+    TRITANOPIA_MODERATE_COLOR,
 };
+#[cfg(feature = "labels")]
+use crate::constants::{DEFAULT_LABEL_COLOR, DEFAULT_LABEL_FONT_SIZE, DEFAULT_LABEL_MARGIN};
+use crate::style::BarStyle;
 
 /// Component to configure a bar
-#[derive(Component, Debug, Clone, Reflect)]
+#[derive(Component, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(default))]
+#[reflect(Component, Default)]
 pub struct BarSettings<T: Percentage + Component + TypePath> {
     /// Configure the width of the bar
     pub width: f32,
@@ -21,13 +34,59 @@ pub struct BarSettings<T: Percentage + Component + TypePath> {
     /// Controls whether the offset is applied in camera space (screen-relative) or world space.
     /// Defaults to [`BarOffsetMode::CameraSpace`] for backward compatibility.
     pub offset_mode: BarOffsetMode,
+    /// Controls how the bar is rotated towards the camera. Defaults to [`BarBillboardMode::Spherical`].
+    /// 2D bars are never rotated towards the camera, but rotate with their entity when set to [`BarBillboardMode::Fixed`].
+    pub billboard_mode: BarBillboardMode,
+    /// Controls how the bar is drawn when it is occluded by other geometry. Defaults to [`BarOcclusion::DepthTested`].
+    /// Ignored by 2D bars.
+    pub occlusion: BarOcclusion,
+    /// Bias added to the depth bars are sorted by. Bars with a higher bias are drawn on top of overlapping bars at a
+    /// similar depth. Defaults to 0. Ignored by 2D bars, which are sorted by the z coordinate of their entity.
+    pub depth_bias: f32,
+    /// Keeps the size of the bar independent of the scale of the entity it is attached to. Defaults to `false`, so
+    /// that bars are scaled along with their entity.
+    pub ignore_parent_scale: bool,
+    /// Derives the offset, and optionally the width, of the bar from the bounds of its entity and its descendants.
+    /// Overwrites [`offset`](Self::offset) and [`width`](Self::width) whenever the bounds change. The placed fields
+    /// take precedence over the same fields of presets, styles and overrides.
+    pub auto_placement: Option<BarAutoPlacement>,
+    /// Optional descendant the bar follows instead of its entity, such as the head bone of an animated model.
+    /// The offset is applied relative to the position of the anchor, while the bar keeps the rotation and scale of its
+    /// entity. Bars follow their entity until the anchor is found.
+    pub anchor: Option<BarAnchor>,
+    /// Keeps the bar at the edge of the viewport while its entity is off-screen, like a waypoint marker.
+    /// Can be disabled per camera through [`HealthBarCameraSettings`](crate::prelude::HealthBarCameraSettings).
+    /// Ignored by 2D bars.
+    pub edge_clamp: Option<BarEdgeClamp>,
+    /// Controls whether the bar is rendered in the world or as a screen overlay. Defaults to
+    /// [`BarRenderMode::Billboard`].
+    pub render_mode: BarRenderMode,
     /// Optional foreground color override for this entity.
     /// If set, takes precedence over the [`ColorScheme`] resource configured for the component type.
     pub foreground_color: Option<ForegroundColor>,
     /// Optional background color override for this entity.
     /// If set, takes precedence over the [`ColorScheme`] resource configured for the component type.
     pub background_color: Option<Color>,
+    /// Optional icon displayed next to the bar.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub icon: Option<BarIcon>,
+    /// Render layers of the bar and its icon. Defaults to the [`RenderLayers`] of the entity the bar is attached to.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub render_layers: Option<RenderLayers>,
+    /// Optional text label displayed on or next to the bar.
+    #[cfg(feature = "labels")]
+    pub label: Option<BarLabel>,
+    /// Optional shared [`BarStyle`] asset. Fields set in the style take precedence over the fields above
+    /// and the preset.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub style: Option<Handle<BarStyle>>,
+    /// Optional name of a preset registered in [`BarStyles`](crate::style::BarStyles).
+    /// Fields set in the preset take precedence over the fields above.
+    pub preset: Option<String>,
+    /// Per-entity overrides applied on top of the preset and style.
+    pub overrides: BarStyle,
     #[reflect(ignore)]
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub phantom_data: PhantomData<T>,
 }
 
@@ -63,6 +122,82 @@ impl<T: Percentage + Component + TypePath> BarSettings<T> {
     pub fn normalized_offset(&self) -> Vec3 {
         self.offset * self.offset_axis()
     }
+
+    /// Edge length of the arrow of edge clamped bars
+    #[cfg(feature = "3d")]
+    pub(crate) fn arrow_size(&self) -> f32 {
+        2. * self.normalized_width().min(self.normalized_height())
+    }
+
+    /// Edge clamp parameters as passed to the shader: margin, arrow size, and the dimensions of the bar.
+    /// Shared by the bar, its icon and its arrow, so that all of them are clamped to the same position.
+    #[cfg(feature = "3d")]
+    pub(crate) fn edge_clamp_parameters(&self) -> Vec4 {
+        let Some(edge_clamp) = &self.edge_clamp else {
+            return Vec4::ZERO;
+        };
+        let arrow_size = match edge_clamp.arrow {
+            Some(_) => self.arrow_size(),
+            None => 0.,
+        };
+
+        Vec4::new(
+            edge_clamp.margin,
+            arrow_size,
+            self.normalized_width(),
+            self.normalized_height(),
+        )
+    }
+
+    /// Whether the bar is drawn as a screen overlay instead of in the world
+    pub(crate) fn is_overlay(&self) -> bool {
+        match self.render_mode {
+            BarRenderMode::Billboard => false,
+            #[cfg(feature = "overlay")]
+            BarRenderMode::ScreenOverlay { .. } => true,
+        }
+    }
+
+    /// Returns the configured render layers, falling back to the render layers of the owner.
+    /// Overlay bars are not rendered by any camera, as they are drawn by the UI instead.
+    pub(crate) fn render_layers(&self, owner: Option<&RenderLayers>) -> Option<RenderLayers> {
+        if self.is_overlay() {
+            return Some(RenderLayers::none());
+        }
+        self.render_layers.clone().or_else(|| owner.cloned())
+    }
+}
+
+// Implemented manually, as deriving would require the tracked component to implement `Clone`
+impl<T: Percentage + Component + TypePath> Clone for BarSettings<T> {
+    fn clone(&self) -> Self {
+        Self {
+            width: self.width,
+            offset: self.offset,
+            height: self.height.clone(),
+            border: self.border.clone(),
+            orientation: self.orientation.clone(),
+            offset_mode: self.offset_mode.clone(),
+            billboard_mode: self.billboard_mode,
+            occlusion: self.occlusion,
+            depth_bias: self.depth_bias,
+            ignore_parent_scale: self.ignore_parent_scale,
+            auto_placement: self.auto_placement.clone(),
+            anchor: self.anchor.clone(),
+            edge_clamp: self.edge_clamp.clone(),
+            render_mode: self.render_mode.clone(),
+            foreground_color: self.foreground_color.clone(),
+            background_color: self.background_color,
+            icon: self.icon.clone(),
+            render_layers: self.render_layers.clone(),
+            #[cfg(feature = "labels")]
+            label: self.label.clone(),
+            style: self.style.clone(),
+            preset: self.preset.clone(),
+            overrides: self.overrides.clone(),
+            phantom_data: PhantomData,
+        }
+    }
 }
 
 impl<T: Percentage + Component + TypePath> Default for BarSettings<T> {
@@ -74,15 +209,101 @@ impl<T: Percentage + Component + TypePath> Default for BarSettings<T> {
             border: default(),
             orientation: default(),
             offset_mode: default(),
+            billboard_mode: default(),
+            occlusion: default(),
+            depth_bias: 0.,
+            ignore_parent_scale: false,
+            auto_placement: None,
+            anchor: None,
+            edge_clamp: None,
+            render_mode: default(),
             foreground_color: None,
             background_color: None,
+            icon: None,
+            render_layers: None,
+            #[cfg(feature = "labels")]
+            label: None,
+            style: None,
+            preset: None,
+            overrides: default(),
             phantom_data: default(),
         }
     }
 }
 
+/// Places a bar next to the bounds of its entity, see [`BarSettings::auto_placement`].
+/// Horizontal bars are placed above the bounds, vertical bars to their right.
+#[derive(Debug, Clone, PartialEq, Default, Reflect)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(default))]
+#[reflect(Default)]
+pub struct BarAutoPlacement {
+    /// Distance between the bounds and the bar
+    pub margin: f32,
+    /// Derives the width of the bar from the extent of the bounds, multiplied by the given factor.
+    /// Horizontal bars use the larger horizontal extent, vertical bars the vertical extent.
+    pub width_factor: Option<f32>,
+}
+
+impl BarAutoPlacement {
+    pub fn new(margin: f32) -> Self {
+        Self {
+            margin,
+            width_factor: None,
+        }
+    }
+
+    pub fn fit_width(mut self, factor: f32) -> Self {
+        self.width_factor = Some(factor);
+        self
+    }
+}
+
+/// Describes the descendant a bar follows, see [`BarSettings::anchor`]
+#[derive(Debug, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum BarAnchor {
+    /// The first descendant with the given [`Name`], for example a bone of a glTF scene.
+    /// Resolved once the descendant is spawned.
+    Name(String),
+    /// The given entity
+    Entity(Entity),
+}
+
+/// Keeps a bar at the edge of the viewport while its entity is off-screen, see [`BarSettings::edge_clamp`]
+#[derive(Debug, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(default))]
+#[reflect(Default)]
+pub struct BarEdgeClamp {
+    /// Distance in pixels between the bar and the edge of the viewport
+    pub margin: f32,
+    /// Color of an optional arrow next to the clamped bar, pointing towards its entity
+    pub arrow: Option<Color>,
+}
+
+impl BarEdgeClamp {
+    pub fn new(margin: f32) -> Self {
+        Self {
+            margin,
+            arrow: None,
+        }
+    }
+
+    pub fn arrow(mut self, color: Color) -> Self {
+        self.arrow = Some(color);
+        self
+    }
+}
+
+impl Default for BarEdgeClamp {
+    fn default() -> Self {
+        Self::new(DEFAULT_EDGE_CLAMP_MARGIN)
+    }
+}
+
 /// Describes the border of a bar. Defaults to no border
 #[derive(Debug, Clone, Reflect)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(default))]
+#[reflect(Default)]
 pub struct BarBorder {
     pub width: f32,
     pub color: Color,
@@ -111,8 +332,61 @@ impl Default for BarBorder {
     }
 }
 
+/// Describes a square icon displayed next to a bar, such as a class or status icon.
+/// The icon is rendered as part of the bar's billboard and follows its offset.
+#[derive(Debug, Clone, Reflect)]
+pub struct BarIcon {
+    pub image: Handle<Image>,
+    /// Edge length of the icon
+    pub size: f32,
+    pub placement: BarIconPlacement,
+    /// Distance between the icon and the bar
+    pub margin: f32,
+}
+
+impl BarIcon {
+    pub fn new(image: Handle<Image>, size: f32) -> Self {
+        Self {
+            image,
+            size,
+            placement: default(),
+            margin: 0.,
+        }
+    }
+
+    pub fn placement(mut self, placement: BarIconPlacement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    pub fn margin(mut self, margin: f32) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Offset of the icon's center from the center of a bar with the given width
+    pub(crate) fn translation(&self, bar_width: f32) -> Vec3 {
+        let distance = bar_width / 2. + self.margin + self.size / 2.;
+        match self.placement {
+            BarIconPlacement::Left => Vec3::new(-distance, 0., 0.),
+            BarIconPlacement::Right => Vec3::new(distance, 0., 0.),
+        }
+    }
+}
+
+/// Describes on which side of the bar a [`BarIcon`] is placed
+#[derive(Reflect, Debug, Clone, PartialEq, Eq, Default)]
+#[reflect(Default)]
+pub enum BarIconPlacement {
+    #[default]
+    Left,
+    Right,
+}
+
 /// Describes the height of the bar
 #[derive(Debug, Clone, Reflect)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[reflect(Default)]
 pub enum BarHeight {
     /// Bar height relative to its width
     Relative(f32),
@@ -129,6 +403,8 @@ impl Default for BarHeight {
 /// Describes the orientation a bar
 /// ```
 #[derive(Reflect, Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[reflect(Default)]
 pub enum BarOrientation {
     #[default]
     Horizontal,
@@ -137,6 +413,8 @@ pub enum BarOrientation {
 
 /// Describes how the bar offset is applied.
 #[derive(Reflect, Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[reflect(Default)]
 pub enum BarOffsetMode {
     /// Offset is applied along the camera's up/right vectors (screen space).
     /// This is the default (legacy) behavior.
@@ -148,10 +426,298 @@ pub enum BarOffsetMode {
     WorldSpace,
 }
 
+/// Describes how a bar is rendered
+#[derive(Reflect, Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[reflect(Default)]
+pub enum BarRenderMode {
+    /// The bar is rendered in the world as a billboard
+    #[default]
+    Billboard,
+    /// The bar is drawn as a UI node over the projected position of its entity, without any depth interaction.
+    /// Its width, height and border width are given in logical pixels, while the offset remains in world units.
+    /// Drawn in the view of the given camera, or the default UI camera if `None`.
+    /// Icons, arrows, edge clamping, occlusion settings and [`BarAccessibility::low_band_pattern`] are ignored.
+    #[cfg(feature = "overlay")]
+    ScreenOverlay { camera: Option<Entity> },
+}
+
+/// Describes how a bar is rotated towards the camera
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[reflect(Default)]
+pub enum BarBillboardMode {
+    /// The bar always fully faces the camera
+    #[default]
+    Spherical,
+    /// The bar stays upright in world space and only rotates around the world Y axis towards the camera
+    Cylindrical,
+    /// The bar is not rotated towards the camera and lies in the XY plane of its transform
+    Fixed,
+}
+
+impl BarBillboardMode {
+    /// Index of the mode as used by the shader
+    #[cfg(feature = "3d")]
+    pub(crate) fn index(&self) -> u32 {
+        match self {
+            BarBillboardMode::Spherical => 0,
+            BarBillboardMode::Cylindrical => 1,
+            BarBillboardMode::Fixed => 2,
+        }
+    }
+}
+
+/// Describes how a bar is drawn when it is occluded by other geometry
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[reflect(Default)]
+pub enum BarOcclusion {
+    /// The bar is hidden behind other geometry
+    #[default]
+    DepthTested,
+    /// The bar is always drawn on top of other geometry
+    AlwaysOnTop,
+    /// The bar is drawn on top of other geometry, occluded parts are dimmed.
+    /// Requires a `DepthPrepass` on the camera,
+    /// without it the bar behaves like [`BarOcclusion::AlwaysOnTop`].
+    XRay,
+}
+
+/// Describes a text label attached to a bar, such as a numeric readout or a name plate.
+///
+/// Labels of 2D bars are spawned as [`Text2d`] children of the bar. Labels of 3D bars are spawned as UI text
+/// that is positioned over the bar in the default UI camera.
+#[cfg(feature = "labels")]
+#[derive(Debug, Clone, Reflect)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(default))]
+#[reflect(Default)]
+pub struct BarLabel {
+    pub content: BarLabelContent,
+    /// Defaults to bevy's default font
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub font: Handle<Font>,
+    /// Font size in logical pixels for 3D bars, and in world units for 2D bars
+    pub font_size: f32,
+    pub color: Color,
+    pub placement: BarLabelPlacement,
+    /// Distance between the label and the bar when placed outside of it
+    pub margin: f32,
+}
+
+#[cfg(feature = "labels")]
+impl BarLabel {
+    pub fn new(content: BarLabelContent) -> Self {
+        Self {
+            content,
+            ..default()
+        }
+    }
+
+    pub fn font(mut self, font: Handle<Font>) -> Self {
+        self.font = font;
+        self
+    }
+
+    pub fn font_size(mut self, font_size: f32) -> Self {
+        self.font_size = font_size;
+        self
+    }
+
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub fn placement(mut self, placement: BarLabelPlacement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    pub fn margin(mut self, margin: f32) -> Self {
+        self.margin = margin;
+        self
+    }
+}
+
+#[cfg(feature = "labels")]
+impl Default for BarLabel {
+    fn default() -> Self {
+        Self {
+            content: default(),
+            font: default(),
+            font_size: DEFAULT_LABEL_FONT_SIZE,
+            color: DEFAULT_LABEL_COLOR,
+            placement: default(),
+            margin: DEFAULT_LABEL_MARGIN,
+        }
+    }
+}
+
+/// Describes the text displayed by a [`BarLabel`]
+#[cfg(feature = "labels")]
+#[derive(Reflect, Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[reflect(Default)]
+pub enum BarLabelContent {
+    /// Displays the tracked value as formatted by [`Percentage::label`], updated whenever the value changes
+    #[default]
+    Value,
+    /// Displays a fixed text, such as the name of a unit
+    Static(String),
+}
+
+/// Describes where a [`BarLabel`] is placed relative to its bar
+#[cfg(feature = "labels")]
+#[derive(Reflect, Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[reflect(Default)]
+pub enum BarLabelPlacement {
+    /// Centered on the bar
+    #[default]
+    Inside,
+    /// Centered above the bar
+    Above,
+    /// To the right of the bar
+    Beside,
+}
+
 /// Trait implemented by the component to be tracked by the health bar
 pub trait Percentage {
     /// Value between 0 and 1
     fn value(&self) -> f32;
+
+    /// Text displayed by labels configured with [`BarLabelContent::Value`], defaults to the value in percent
+    fn label(&self) -> String {
+        format!("{:.0}%", self.value() * 100.)
+    }
+}
+
+/// Trait for tracked components with an absolute current and maximum value.
+/// Types implementing it get an implementation of [`Percentage`] for free.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_bar3d::prelude::{BarValue, Percentage};
+///
+/// #[derive(Component, Reflect)]
+/// struct Health {
+///     max: f32,
+///     current: f32,
+/// }
+///
+/// impl BarValue for Health {
+///     fn current(&self) -> f32 {
+///         self.current
+///     }
+///
+///     fn max(&self) -> f32 {
+///         self.max
+///     }
+/// }
+///
+/// let health = Health { max: 100., current: 75. };
+/// assert_eq!(health.value(), 0.75);
+/// assert_eq!(health.format_label(), "75 / 100");
+/// ```
+pub trait BarValue {
+    fn current(&self) -> f32;
+
+    fn max(&self) -> f32;
+
+    /// Formats the value for display, defaults to `"<current> / <max>"`
+    fn format_label(&self) -> String {
+        format!("{:.0} / {:.0}", self.current(), self.max())
+    }
+}
+
+impl<V: BarValue> Percentage for V {
+    fn value(&self) -> f32 {
+        let max = self.max();
+        if max <= 0. {
+            return 0.;
+        }
+
+        (self.current() / max).clamp(0., 1.)
+    }
+
+    fn label(&self) -> String {
+        self.format_label()
+    }
+}
+
+/// Generic component for values with a current and a maximum, tracked by implementing [`BarValue`].
+/// The marker type distinguishes several stats on the same entity.
+///
+/// # Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_health_bar3d::prelude::{BarSettings, HealthBarPlugin, Stat};
+///
+/// #[derive(TypePath)]
+/// struct HealthStat;
+///
+/// #[derive(TypePath)]
+/// struct ManaStat;
+///
+/// type Health = Stat<HealthStat>;
+/// type Mana = Stat<ManaStat>;
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins((
+///             DefaultPlugins,
+///             HealthBarPlugin::<Health>::default(),
+///             HealthBarPlugin::<Mana>::default(),
+///         ))
+///         .add_systems(Startup, setup)
+///         .run();
+/// }
+///
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         Health::new(75., 100.),
+///         BarSettings::<Health>::default(),
+///         Mana::full(50.),
+///         BarSettings::<Mana>::default(),
+///     ));
+/// }
+/// ```
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+pub struct Stat<M: TypePath + Send + Sync = ()> {
+    pub current: f32,
+    pub max: f32,
+    #[reflect(ignore)]
+    pub phantom_data: PhantomData<M>,
+}
+
+impl<M: TypePath + Send + Sync> Stat<M> {
+    pub fn new(current: f32, max: f32) -> Self {
+        Self {
+            current,
+            max,
+            phantom_data: PhantomData,
+        }
+    }
+
+    /// Returns a stat with its current value set to the maximum
+    pub fn full(max: f32) -> Self {
+        Self::new(max, max)
+    }
+}
+
+impl<M: TypePath + Send + Sync> BarValue for Stat<M> {
+    fn current(&self) -> f32 {
+        self.current
+    }
+
+    fn max(&self) -> f32 {
+        self.max
+    }
 }
 
 /// ForegroundColor enum. The foreground color can either be static or a tri-color spectrum
@@ -159,6 +725,7 @@ pub trait Percentage {
 /// The high color is applied when the tracked component's value is more than or equal to 80%,
 /// moderate when it's between 40% and 80%, and low when it is less than 40%.
 #[derive(Debug, Clone, Reflect)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ForegroundColor {
     Static(Color),
     TriSpectrum {
@@ -169,21 +736,45 @@ pub enum ForegroundColor {
 }
 
 /// Resource to customize the appearance of bars per tracked component type.
-#[derive(Resource, Debug, Clone, Reflect)]
+#[derive(Resource, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(default))]
+#[reflect(Resource, Default)]
 pub struct ColorScheme<T: Percentage + Component + TypePath> {
     pub foreground_color: ForegroundColor,
     pub background_color: Color,
     #[reflect(ignore)]
+    #[cfg_attr(feature = "serde", serde(skip))]
     phantom_data: PhantomData<T>,
 }
 
+// `T` is only a marker of the scheme, so it is left out of the bounds. `HealthBarPlugin::build` clones the
+// configured scheme into the app, which works for tracked components that aren't `Clone`.
+impl<T: Percentage + Component + TypePath> Clone for ColorScheme<T> {
+    fn clone(&self) -> Self {
+        Self {
+            foreground_color: self.foreground_color.clone(),
+            background_color: self.background_color,
+            phantom_data: PhantomData,
+        }
+    }
+}
+
 impl<T: Percentage + Component + TypePath> ColorScheme<T> {
     /// Returns a default initialized ColorScheme for the given component type
     ///
     /// # Examples
     ///
     /// ```
+    /// # use bevy::prelude::*;
     /// use bevy_health_bar3d::prelude::ColorScheme;
+    /// # use bevy_health_bar3d::prelude::Percentage;
+    /// # #[derive(Component, TypePath)]
+    /// # struct Health(f32);
+    /// # impl Percentage for Health {
+    /// #     fn value(&self) -> f32 {
+    /// #         self.0
+    /// #     }
+    /// # }
     /// let color_scheme = ColorScheme::<Health>::new();
     /// ```
     pub fn new() -> Self {
@@ -203,8 +794,24 @@ impl<T: Percentage + Component + TypePath> ColorScheme<T> {
     /// # Examples
     ///
     /// ```
-    /// use bevy::prelude::Color;
+    /// use bevy::color::palettes::basic::{BLUE, GREEN, RED, YELLOW};
+    /// # use bevy::prelude::*;
     /// use bevy_health_bar3d::prelude::{ColorScheme, ForegroundColor};
+    /// # use bevy_health_bar3d::prelude::Percentage;
+    /// # #[derive(Component, TypePath)]
+    /// # struct Health(f32);
+    /// # impl Percentage for Health {
+    /// #     fn value(&self) -> f32 {
+    /// #         self.0
+    /// #     }
+    /// # }
+    /// # #[derive(Component, TypePath)]
+    /// # struct Mana(f32);
+    /// # impl Percentage for Mana {
+    /// #     fn value(&self) -> f32 {
+    /// #         self.0
+    /// #     }
+    /// # }
     /// let mana_scheme = ColorScheme::<Mana>::new().foreground_color(ForegroundColor::Static(BLUE.into()));
     /// let health_scheme = ColorScheme::<Health>::new().foreground_color(ForegroundColor::TriSpectrum {
     ///     high: GREEN.into(),
@@ -216,6 +823,33 @@ impl<T: Percentage + Component + TypePath> ColorScheme<T> {
         self.foreground_color = color;
         self
     }
+
+    /// Returns a ColorScheme using the given accessible palette
+    pub fn palette(palette: BarPalette) -> Self {
+        Self::new()
+            .foreground_color(palette.foreground_color())
+            .background_color(palette.background_color())
+    }
+
+    /// Returns a ColorScheme distinguishable with deuteranopia
+    pub fn deuteranopia() -> Self {
+        Self::palette(BarPalette::Deuteranopia)
+    }
+
+    /// Returns a ColorScheme distinguishable with protanopia
+    pub fn protanopia() -> Self {
+        Self::palette(BarPalette::Protanopia)
+    }
+
+    /// Returns a ColorScheme distinguishable with tritanopia
+    pub fn tritanopia() -> Self {
+        Self::palette(BarPalette::Tritanopia)
+    }
+
+    /// Returns a ColorScheme with high contrast colors on an opaque background
+    pub fn high_contrast() -> Self {
+        Self::palette(BarPalette::HighContrast)
+    }
 }
 
 impl<T: Percentage + Component + TypePath> Default for ColorScheme<T> {
@@ -231,3 +865,87 @@ impl<T: Percentage + Component + TypePath> Default for ColorScheme<T> {
         }
     }
 }
+
+/// Built-in color palettes for players with color vision deficiencies
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum BarPalette {
+    Deuteranopia,
+    Protanopia,
+    Tritanopia,
+    HighContrast,
+}
+
+impl BarPalette {
+    pub fn foreground_color(&self) -> ForegroundColor {
+        let (high, moderate, low) = match self {
+            BarPalette::Deuteranopia => (
+                DEUTERANOPIA_HIGH_COLOR,
+                DEUTERANOPIA_MODERATE_COLOR,
+                DEUTERANOPIA_LOW_COLOR,
+            ),
+            BarPalette::Protanopia => (
+                PROTANOPIA_HIGH_COLOR,
+                PROTANOPIA_MODERATE_COLOR,
+                PROTANOPIA_LOW_COLOR,
+            ),
+            BarPalette::Tritanopia => (
+                TRITANOPIA_HIGH_COLOR,
+                TRITANOPIA_MODERATE_COLOR,
+                TRITANOPIA_LOW_COLOR,
+            ),
+            BarPalette::HighContrast => (
+                HIGH_CONTRAST_HIGH_COLOR,
+                HIGH_CONTRAST_MODERATE_COLOR,
+                HIGH_CONTRAST_LOW_COLOR,
+            ),
+        };
+
+        ForegroundColor::TriSpectrum {
+            high,
+            moderate,
+            low,
+        }
+    }
+
+    pub fn background_color(&self) -> Color {
+        match self {
+            BarPalette::HighContrast => HIGH_CONTRAST_BACKGROUND_COLOR,
+            _ => DEFAULT_BACKGROUND_COLOR,
+        }
+    }
+}
+
+/// Resource to configure accessibility options for the bars of all tracked component types at once
+#[derive(Resource, Debug, Clone, Default, Reflect)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(default))]
+#[reflect(Resource, Default)]
+pub struct BarAccessibility {
+    /// If set, the palette takes precedence over the [`ColorScheme`] of every tracked component type.
+    /// Per-entity color overrides in [`BarSettings`] are still applied.
+    pub palette: Option<BarPalette>,
+    /// Adds a striped pattern to bars in the low band, so that it does not depend on color alone.
+    /// Not drawn by screen overlays, see [`BarRenderMode`].
+    pub low_band_pattern: bool,
+}
+
+/// Resource to show, hide or pause the bars of all tracked component types at once, for example during cutscenes
+#[derive(Resource, Debug, Clone, Reflect)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(default))]
+#[reflect(Resource, Default)]
+pub struct HealthBarGlobalSettings {
+    /// Hides all bars when `false`. Bars are restored to their own [`Visibility`] when shown again, including
+    /// changes made to it while hidden.
+    pub visible: bool,
+    /// Stops syncing bars with their tracked components and settings. Changes are applied once unpaused.
+    pub paused: bool,
+}
+
+impl Default for HealthBarGlobalSettings {
+    fn default() -> Self {
+        Self {
+            visible: true,
+            paused: false,
+        }
+    }
+}
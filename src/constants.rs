@@ -7,8 +7,49 @@ pub const DEFAULT_HIGH_COLOR: Color = Color::srgba(0., 1., 0., 0.95);
 pub const DEFAULT_MODERATE_COLOR: Color = Color::srgba(1., 1., 0., 0.95);
 pub const DEFAULT_LOW_COLOR: Color = Color::srgba(1., 0., 0., 0.95);
 
+/// Blue to orange palette, distinguishable with deuteranopia (red-green, green weak)
+pub const DEUTERANOPIA_HIGH_COLOR: Color = Color::srgba(0., 0.447, 0.698, 0.95);
+pub const DEUTERANOPIA_MODERATE_COLOR: Color = Color::srgba(0.941, 0.894, 0.259, 0.95);
+pub const DEUTERANOPIA_LOW_COLOR: Color = Color::srgba(0.835, 0.369, 0., 0.95);
+
+/// Sky blue to orange palette, distinguishable with protanopia (red-green, red weak).
+/// Red is avoided, as it appears dark to protanopes
+pub const PROTANOPIA_HIGH_COLOR: Color = Color::srgba(0.337, 0.706, 0.914, 0.95);
+pub const PROTANOPIA_MODERATE_COLOR: Color = Color::srgba(0.941, 0.894, 0.259, 0.95);
+pub const PROTANOPIA_LOW_COLOR: Color = Color::srgba(0.902, 0.624, 0., 0.95);
+
+/// Cyan to red palette, distinguishable with tritanopia (blue-yellow)
+pub const TRITANOPIA_HIGH_COLOR: Color = Color::srgba(0., 0.62, 0.62, 0.95);
+pub const TRITANOPIA_MODERATE_COLOR: Color = Color::srgba(0.957, 0.561, 0.694, 0.95);
+pub const TRITANOPIA_LOW_COLOR: Color = Color::srgba(0.8, 0.1, 0.1, 0.95);
+
+/// Palette with maximum luminance contrast against an opaque black background
+pub const HIGH_CONTRAST_HIGH_COLOR: Color = Color::WHITE;
+pub const HIGH_CONTRAST_MODERATE_COLOR: Color = Color::srgb(1., 0.843, 0.);
+pub const HIGH_CONTRAST_LOW_COLOR: Color = Color::srgb(1., 0., 1.);
+pub const HIGH_CONTRAST_BACKGROUND_COLOR: Color = Color::BLACK;
+
 pub const DEFAULT_WIDTH: f32 = 1.2;
 pub const DEFAULT_RELATIVE_HEIGHT: f32 = 0.1666;
+/// Distance in pixels between edge clamped bars and the edge of the viewport
+pub const DEFAULT_EDGE_CLAMP_MARGIN: f32 = 16.;
+
+#[cfg(feature = "labels")]
+pub const DEFAULT_LABEL_FONT_SIZE: f32 = 14.;
+#[cfg(feature = "labels")]
+pub const DEFAULT_LABEL_COLOR: Color = Color::WHITE;
+#[cfg(feature = "labels")]
+pub const DEFAULT_LABEL_MARGIN: f32 = 2.;
+
+/// Values greater than or equal to this threshold are displayed in the high color
+pub const HIGH_THRESHOLD: f32 = 0.8;
+/// Values less than this threshold are displayed in the low color
+pub const LOW_THRESHOLD: f32 = 0.4;
+
+/// Shader module with the fill, border and color logic shared by 2D and 3D bars. Can be imported as
+/// `bevy_health_bar3d::bar` by custom materials.
+pub(crate) const BAR_COMMON_SHADER_HANDLE: Handle<Shader> =
+    uuid_handle!("e63c5779-c2af-6a78-d4cc-92a049cdc0cc");
 
 #[cfg(feature = "3d")]
 pub(crate) const BAR_SHADER_HANDLE: Handle<Shader> =
@@ -17,3 +58,9 @@ pub(crate) const BAR_SHADER_HANDLE: Handle<Shader> =
 #[cfg(feature = "2d")]
 pub(crate) const BAR_SHADER_2D_HANDLE: Handle<Shader> =
     uuid_handle!("d52b4668-b19e-5f67-c3bb-819f38bcbfbb");
+
+/// Texture holding the settings of all cameras with
+/// [`HealthBarCameraSettings`](crate::prelude::HealthBarCameraSettings), shared by all 3D bars
+#[cfg(feature = "3d")]
+pub(crate) const BAR_CAMERAS_TEXTURE_HANDLE: Handle<Image> =
+    uuid_handle!("a7f3c2e1-5b84-4d19-9e6a-3c0d8f2b7e45");
@@ -0,0 +1,156 @@
+#[cfg(feature = "3d")]
+use bevy::camera::visibility::NoFrustumCulling;
+use bevy::camera::visibility::RenderLayers;
+#[cfg(feature = "3d")]
+use bevy::light::{NotShadowCaster, NotShadowReceiver};
+use bevy::prelude::*;
+
+use crate::configuration::{BarIcon, Percentage};
+use crate::mesh::MeshHandles;
+use crate::plugin::{Material, MaterialComponent, MeshComponent, SettingsChanged};
+use crate::prelude::{BarOffsetMode, BarSettings};
+use crate::relationship::{Attached, HasAttached, HasBar};
+use crate::style::StyleResolver;
+
+/// Marks entities attached to a bar as its icon
+pub(crate) struct IconAttachment;
+
+pub(crate) type IconOf = Attached<IconAttachment>;
+pub(crate) type HasIcon = HasAttached<IconAttachment>;
+
+/// Returns the square mesh of an icon, translated next to the bar
+fn icon_mesh<T: Percentage + Component + TypePath>(
+    icon: &BarIcon,
+    settings: &BarSettings<T>,
+    meshes: &mut Assets<Mesh>,
+    mesh_handles: &mut MeshHandles,
+) -> Handle<Mesh> {
+    let translation = icon.translation(settings.normalized_width());
+
+    mesh_handles
+        .get_translated(icon.size, icon.size, translation.x)
+        .unwrap_or_else(|| {
+            let handle = meshes
+                .add(Mesh::from(Rectangle::new(icon.size, icon.size)).translated_by(translation));
+            mesh_handles.insert_translated(icon.size, icon.size, translation.x, handle.clone());
+            handle
+        })
+}
+
+fn apply_icon<T: Percentage + Component + TypePath>(
+    material: &mut Material,
+    icon: &BarIcon,
+    settings: &BarSettings<T>,
+) {
+    material.value_and_dimensions = Vec4::new(1., icon.size, icon.size, 0.);
+    material.icon = Some(icon.image.clone());
+    material.offset = settings.normalized_offset().extend(0.);
+    material.world_space_offset = settings.offset_mode == BarOffsetMode::WorldSpace;
+    #[cfg(feature = "3d")]
+    {
+        material.billboard_mode = settings.billboard_mode;
+        material.occlusion = settings.occlusion;
+        material.depth_bias = settings.depth_bias;
+        material.edge_clamp = settings.edge_clamp_parameters();
+        material.clamp_to_edge = settings.edge_clamp.is_some();
+    }
+}
+
+pub(crate) fn spawn_icon<T: Percentage + Component + TypePath>(
+    commands: &mut Commands,
+    materials: &mut Assets<Material>,
+    meshes: &mut Assets<Mesh>,
+    mesh_handles: &mut MeshHandles,
+    bar: Entity,
+    settings: &BarSettings<T>,
+    render_layers: Option<&RenderLayers>,
+) {
+    let Some(icon) = &settings.icon else {
+        return;
+    };
+
+    let mesh = icon_mesh(icon, settings, meshes, mesh_handles);
+    let mut material = Material::default();
+    apply_icon(&mut material, icon, settings);
+    let material = materials.add(material);
+
+    #[cfg(feature = "3d")]
+    let mut icon = commands.spawn((
+        Name::new(format!("{}Icon", T::type_path())),
+        Mesh3d(mesh),
+        MeshMaterial3d(material),
+        NotShadowCaster,
+        NotShadowReceiver,
+        IconOf::new(bar),
+        ChildOf(bar),
+    ));
+
+    #[cfg(feature = "2d")]
+    let mut icon = commands.spawn((
+        Name::new(format!("{}Icon", T::type_path())),
+        Mesh2d(mesh),
+        MeshMaterial2d(material),
+        IconOf::new(bar),
+        ChildOf(bar),
+    ));
+
+    if let Some(render_layers) = render_layers {
+        icon.insert(render_layers.clone());
+    }
+
+    #[cfg(feature = "3d")]
+    if settings.edge_clamp.is_some() {
+        icon.insert(NoFrustumCulling);
+    }
+}
+
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+pub(crate) fn update_icon_settings<T: Percentage + Component + TypePath>(
+    mut commands: Commands,
+    mut materials: ResMut<Assets<Material>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut mesh_handles: ResMut<MeshHandles>,
+    styles: StyleResolver,
+    parent_query: Query<(&HasBar<T>, &BarSettings<T>), SettingsChanged<T>>,
+    bar_query: Query<(Option<&HasIcon>, Option<&RenderLayers>)>,
+    icon_query: Query<&MaterialComponent>,
+) {
+    parent_query.iter().for_each(|(bar, settings)| {
+        let Ok((has_icon, render_layers)) = bar_query.get(bar.bar()) else {
+            return;
+        };
+        let settings = styles.resolve(settings);
+
+        match (has_icon, &settings.icon) {
+            (Some(has_icon), Some(icon)) => {
+                let Ok(material_handle) = icon_query.get(has_icon.get()) else {
+                    return;
+                };
+
+                let material = materials.get_mut(&material_handle.0).unwrap();
+                apply_icon(material, icon, &settings);
+
+                let mesh = icon_mesh(icon, &settings, &mut meshes, &mut mesh_handles);
+                let mut icon = commands.entity(has_icon.get());
+                icon.insert(MeshComponent::from(mesh));
+
+                #[cfg(feature = "3d")]
+                match settings.edge_clamp {
+                    Some(_) => icon.insert(NoFrustumCulling),
+                    None => icon.remove::<NoFrustumCulling>(),
+                };
+            }
+            (Some(has_icon), None) => commands.entity(has_icon.get()).despawn(),
+            (None, Some(_)) => spawn_icon(
+                &mut commands,
+                &mut materials,
+                &mut meshes,
+                &mut mesh_handles,
+                bar.bar(),
+                &settings,
+                render_layers,
+            ),
+            (None, None) => {}
+        }
+    });
+}
@@ -0,0 +1,300 @@
+use bevy::prelude::*;
+#[cfg(feature = "2d")]
+use bevy::sprite::{Anchor, Text2d};
+#[cfg(feature = "3d")]
+use bevy::{
+    camera::visibility::RenderLayers, transform::helper::TransformHelper, ui::DefaultUiCamera,
+};
+
+#[cfg(feature = "3d")]
+use crate::camera::HealthBarCameraSettings;
+use crate::configuration::{BarLabel, BarLabelContent, BarLabelPlacement, Percentage};
+use crate::plugin::SettingsChanged;
+use crate::prelude::BarSettings;
+use crate::relationship::{Attached, HasAttached, HasBar};
+#[cfg(feature = "3d")]
+use crate::screen::screen_node;
+use crate::screen::ViewOffset;
+use crate::style::StyleResolver;
+
+#[cfg(feature = "3d")]
+type LabelText = Text;
+#[cfg(feature = "2d")]
+type LabelText = Text2d;
+
+/// Local z coordinate of 2D labels, placing them in front of their bar
+#[cfg(feature = "2d")]
+const LABEL_Z: f32 = 0.1;
+
+/// Marks entities attached to a bar as its label
+pub(crate) struct LabelAttachment;
+
+pub(crate) type LabelOf = Attached<LabelAttachment>;
+pub(crate) type HasLabel = HasAttached<LabelAttachment>;
+
+/// Describes where a 3D label is positioned on screen relative to its bar
+#[cfg(feature = "3d")]
+#[derive(Component)]
+pub(crate) struct LabelAnchor {
+    /// Point on the bar the label is attached to, relative to the bar center along the camera's right and up vectors
+    point: Vec2,
+    /// Alignment of the label relative to the projected point, where (0, 0) is the label's top left corner
+    alignment: Vec2,
+    /// Screen space distance between the projected point and the label in logical pixels
+    margin: Vec2,
+    offset: ViewOffset,
+    /// Whether the bar is drawn as an overlay, which is shown regardless of the render layers of the bar
+    overlay: bool,
+}
+
+#[cfg(feature = "3d")]
+impl LabelAnchor {
+    fn new<T: Percentage + Component + TypePath>(
+        label: &BarLabel,
+        settings: &BarSettings<T>,
+    ) -> Self {
+        let half_size = Vec2::new(settings.normalized_width(), settings.normalized_height()) / 2.;
+        // overlay bars are sized in pixels rather than world units
+        let (half_size, pixel_half_size) = if settings.is_overlay() {
+            (Vec2::ZERO, half_size)
+        } else {
+            (half_size, Vec2::ZERO)
+        };
+        let (point, alignment, margin) = match label.placement {
+            BarLabelPlacement::Inside => (Vec2::ZERO, Vec2::splat(0.5), Vec2::ZERO),
+            BarLabelPlacement::Above => (
+                Vec2::new(0., half_size.y),
+                Vec2::new(0.5, 1.),
+                Vec2::new(0., -label.margin - pixel_half_size.y),
+            ),
+            BarLabelPlacement::Beside => (
+                Vec2::new(half_size.x, 0.),
+                Vec2::new(0., 0.5),
+                Vec2::new(label.margin + pixel_half_size.x, 0.),
+            ),
+        };
+
+        Self {
+            point,
+            alignment,
+            margin,
+            offset: ViewOffset::new(settings),
+            overlay: settings.is_overlay(),
+        }
+    }
+
+    /// Returns the world position of the anchor point for a bar seen from the given camera
+    fn world_position(&self, bar: &GlobalTransform, camera: &GlobalTransform) -> Vec3 {
+        self.offset.world_position(self.point, bar, camera)
+    }
+}
+
+fn label_text<T: Percentage>(label: &BarLabel, percentage: &T) -> String {
+    match &label.content {
+        BarLabelContent::Value => percentage.label(),
+        BarLabelContent::Static(text) => text.clone(),
+    }
+}
+
+#[cfg(feature = "3d")]
+fn label_bundle<T: Percentage + Component + TypePath>(
+    label: &BarLabel,
+    percentage: &T,
+    settings: &BarSettings<T>,
+) -> impl Bundle {
+    (
+        Name::new(format!("{}Label", T::type_path())),
+        Text::new(label_text(label, percentage)),
+        TextFont {
+            font: label.font.clone(),
+            font_size: label.font_size,
+            ..default()
+        },
+        TextColor(label.color),
+        screen_node(default()),
+        LabelAnchor::new(label, settings),
+    )
+}
+
+#[cfg(feature = "2d")]
+fn label_bundle<T: Percentage + Component + TypePath>(
+    label: &BarLabel,
+    percentage: &T,
+    settings: &BarSettings<T>,
+) -> impl Bundle {
+    let half_size = Vec2::new(settings.normalized_width(), settings.normalized_height()) / 2.;
+    let (position, anchor) = match label.placement {
+        BarLabelPlacement::Inside => (Vec2::ZERO, Anchor::CENTER),
+        BarLabelPlacement::Above => (
+            Vec2::new(0., half_size.y + label.margin),
+            Anchor::BOTTOM_CENTER,
+        ),
+        BarLabelPlacement::Beside => (
+            Vec2::new(half_size.x + label.margin, 0.),
+            Anchor::CENTER_LEFT,
+        ),
+    };
+    let offset = ViewOffset::new(settings).get();
+
+    (
+        Name::new(format!("{}Label", T::type_path())),
+        Text2d::new(label_text(label, percentage)),
+        TextFont {
+            font: label.font.clone(),
+            font_size: label.font_size,
+            ..default()
+        },
+        TextColor(label.color),
+        anchor,
+        Transform::from_translation((position + offset).extend(LABEL_Z)),
+    )
+}
+
+pub(crate) fn spawn_label<T: Percentage + Component + TypePath>(
+    commands: &mut Commands,
+    bar: Entity,
+    percentage: &T,
+    settings: &BarSettings<T>,
+) {
+    let Some(label) = &settings.label else {
+        return;
+    };
+
+    #[cfg(feature = "3d")]
+    commands.spawn((label_bundle(label, percentage, settings), LabelOf::new(bar)));
+
+    #[cfg(feature = "2d")]
+    commands.spawn((
+        label_bundle(label, percentage, settings),
+        LabelOf::new(bar),
+        ChildOf(bar),
+    ));
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn update_labels<T: Percentage + Component + TypePath>(
+    parent_query: Query<(&HasBar<T>, &T, &BarSettings<T>), Changed<T>>,
+    bar_query: Query<&HasLabel>,
+    mut label_query: Query<&mut LabelText>,
+) {
+    parent_query.iter().for_each(|(bar, percentage, settings)| {
+        let Some(label) = &settings.label else {
+            return;
+        };
+
+        if label.content != BarLabelContent::Value {
+            return;
+        }
+
+        let Ok(mut text) = bar_query
+            .get(bar.bar())
+            .and_then(|has_label| label_query.get_mut(has_label.get()))
+        else {
+            return;
+        };
+
+        let value = percentage.label();
+        if text.0 != value {
+            text.0 = value;
+        }
+    });
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn update_label_settings<T: Percentage + Component + TypePath>(
+    mut commands: Commands,
+    styles: StyleResolver,
+    parent_query: Query<(&HasBar<T>, &T, &BarSettings<T>), SettingsChanged<T>>,
+    bar_query: Query<Option<&HasLabel>>,
+) {
+    parent_query.iter().for_each(|(bar, percentage, settings)| {
+        let Ok(has_label) = bar_query.get(bar.bar()) else {
+            return;
+        };
+        let settings = styles.resolve(settings);
+
+        match (has_label, &settings.label) {
+            (Some(has_label), Some(label)) => {
+                commands
+                    .entity(has_label.get())
+                    .insert(label_bundle(label, percentage, &settings));
+            }
+            (Some(has_label), None) => commands.entity(has_label.get()).despawn(),
+            (None, Some(_)) => spawn_label(&mut commands, bar.bar(), percentage, &settings),
+            (None, None) => {}
+        }
+    });
+}
+
+/// Positions 3D labels over their bar as seen from the default UI camera. Labels are hidden if their bar is not
+/// rendered by that camera, because of its render layers or [`HealthBarCameraSettings::hidden`].
+#[cfg(feature = "3d")]
+#[allow(clippy::type_complexity)]
+pub(crate) fn position_labels(
+    ui_camera: DefaultUiCamera,
+    camera_query: Query<(
+        &Camera,
+        Option<&RenderLayers>,
+        Option<&HealthBarCameraSettings>,
+    )>,
+    transform_helper: TransformHelper,
+    bar_query: Query<(&InheritedVisibility, Option<&RenderLayers>)>,
+    mut label_query: Query<(
+        &LabelOf,
+        &LabelAnchor,
+        &ComputedNode,
+        &mut Node,
+        &mut Visibility,
+    )>,
+) {
+    let Some(((camera, camera_layers, camera_settings), camera_transform)) =
+        ui_camera.get().and_then(|entity| {
+            Some((
+                camera_query.get(entity).ok()?,
+                transform_helper.compute_global_transform(entity).ok()?,
+            ))
+        })
+    else {
+        return;
+    };
+    let camera_layers = camera_layers.cloned().unwrap_or_default();
+    let camera_hidden = camera_settings.is_some_and(|settings| settings.hidden);
+
+    for (label_of, anchor, computed_node, mut node, mut visibility) in label_query.iter_mut() {
+        let visible = !camera_hidden
+            && bar_query
+                .get(label_of.bar())
+                .is_ok_and(|(visibility, bar_layers)| {
+                    let rendered = anchor.overlay
+                        || bar_layers
+                            .cloned()
+                            .unwrap_or_default()
+                            .intersects(&camera_layers);
+                    visibility.get() && rendered
+                });
+
+        let viewport_position = transform_helper
+            .compute_global_transform(label_of.bar())
+            .ok()
+            .filter(|_| visible)
+            .and_then(|bar_transform| {
+                let position = anchor.world_position(&bar_transform, &camera_transform);
+                camera.world_to_viewport(&camera_transform, position).ok()
+            });
+
+        let Some(viewport_position) = viewport_position else {
+            visibility.set_if_neq(Visibility::Hidden);
+            continue;
+        };
+
+        let size = computed_node.size() * computed_node.inverse_scale_factor();
+        let position = viewport_position + anchor.margin - size * anchor.alignment;
+
+        let (left, top) = (Val::Px(position.x), Val::Px(position.y));
+        if node.left != left || node.top != top {
+            node.left = left;
+            node.top = top;
+        }
+        visibility.set_if_neq(Visibility::Inherited);
+    }
+}
@@ -1,13 +1,37 @@
+mod anchor;
+#[cfg(feature = "3d")]
+mod arrow;
+#[cfg(feature = "3d")]
+mod camera;
 pub mod configuration;
 pub mod constants;
+mod icon;
+#[cfg(feature = "labels")]
+mod label;
 #[cfg(feature = "3d")]
 mod material;
 #[cfg(feature = "2d")]
 mod material2d;
 mod mesh;
+pub mod messages;
+#[cfg(feature = "overlay")]
+mod overlay;
+mod placement;
 pub mod plugin;
+pub mod relationship;
+#[cfg(any(feature = "labels", feature = "overlay"))]
+mod screen;
+pub mod style;
+#[cfg(feature = "testing")]
+#[doc(hidden)]
+pub mod testing;
 
 pub mod prelude {
+    #[cfg(feature = "3d")]
+    pub use crate::camera::{HealthBarCameraSettings, MAX_BAR_CAMERAS};
     pub use crate::configuration::*;
-    pub use crate::plugin::HealthBarPlugin;
+    pub use crate::messages::*;
+    pub use crate::plugin::{HealthBarPlugin, HealthBarSystems};
+    pub use crate::relationship::{BarOf, Bars, HasBar};
+    pub use crate::style::*;
 }
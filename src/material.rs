@@ -2,12 +2,14 @@ use bevy::mesh::MeshVertexBufferLayoutRef;
 use bevy::pbr::{MaterialPipeline, MaterialPipelineKey};
 use bevy::prelude::*;
 use bevy::render::render_resource::{
-    AsBindGroup, RenderPipelineDescriptor, SpecializedMeshPipelineError,
+    AsBindGroup, CompareFunction, RenderPipelineDescriptor, SpecializedMeshPipelineError,
 };
 use bevy::shader::ShaderRef;
 
-use crate::constants::BAR_SHADER_HANDLE;
+use crate::configuration::{BarBillboardMode, BarOcclusion};
+use crate::constants::{BAR_CAMERAS_TEXTURE_HANDLE, BAR_SHADER_HANDLE};
 
+/// Material of 3D bars and their icons
 #[derive(Asset, AsBindGroup, Debug, Clone, Reflect)]
 #[bind_group_data(BarMaterialKey)]
 pub(crate) struct BarMaterial {
@@ -26,23 +28,74 @@ pub(crate) struct BarMaterial {
     pub offset: Vec4,
     #[uniform(6)]
     pub border_color: LinearRgba,
+    #[texture(7)]
+    #[sampler(8)]
+    pub icon: Option<Handle<Image>>,
+    #[texture(9, sample_type = "float", filterable = false)]
+    pub(crate) cameras: Handle<Image>,
+    #[uniform(10)]
+    pub edge_clamp: Vec4,
+    // (margin, arrow_size, bar_width, bar_height)
+    pub clamp_to_edge: bool,
+    pub arrow: bool,
     pub vertical: bool,
+    pub low_band_pattern: bool,
+    pub depth_bias: f32,
     pub world_space_offset: bool,
+    pub billboard_mode: BarBillboardMode,
+    pub occlusion: BarOcclusion,
 }
 
+// Implemented manually, so that all materials reference the shared camera texture
+impl Default for BarMaterial {
+    fn default() -> Self {
+        Self {
+            value_and_dimensions: default(),
+            background_color: default(),
+            high_color: default(),
+            moderate_color: default(),
+            low_color: default(),
+            offset: default(),
+            border_color: default(),
+            icon: None,
+            cameras: BAR_CAMERAS_TEXTURE_HANDLE,
+            edge_clamp: default(),
+            clamp_to_edge: false,
+            arrow: false,
+            vertical: false,
+            low_band_pattern: false,
+            depth_bias: 0.,
+            world_space_offset: false,
+            billboard_mode: default(),
+            occlusion: default(),
+        }
+    }
+}
+
+/// Pipeline specialization key of [`BarMaterial`]
 #[derive(Eq, PartialEq, Hash, Clone)]
 pub(crate) struct BarMaterialKey {
     vertical: bool,
-    border: bool,
     world_space_offset: bool,
+    billboard_mode: BarBillboardMode,
+    occlusion: BarOcclusion,
+    icon: bool,
+    low_band_pattern: bool,
+    clamp_to_edge: bool,
+    arrow: bool,
 }
 
 impl From<&BarMaterial> for BarMaterialKey {
     fn from(material: &BarMaterial) -> Self {
         Self {
             vertical: material.vertical,
-            border: material.value_and_dimensions.w > 0.,
             world_space_offset: material.world_space_offset,
+            billboard_mode: material.billboard_mode,
+            occlusion: material.occlusion,
+            icon: material.icon.is_some(),
+            low_band_pattern: material.low_band_pattern,
+            clamp_to_edge: material.clamp_to_edge,
+            arrow: material.arrow,
         }
     }
 }
@@ -56,6 +109,10 @@ impl Material for BarMaterial {
         BAR_SHADER_HANDLE.into()
     }
 
+    fn depth_bias(&self) -> f32 {
+        self.depth_bias
+    }
+
     fn alpha_mode(&self) -> AlphaMode {
         AlphaMode::Blend
     }
@@ -76,8 +133,25 @@ impl Material for BarMaterial {
             fragment.shader_defs.push("IS_VERTICAL".into());
         }
 
-        if key.bind_group_data.border {
-            fragment.shader_defs.push("HAS_BORDER".into());
+        if key.bind_group_data.icon {
+            fragment.shader_defs.push("IS_ICON".into());
+        }
+
+        if key.bind_group_data.low_band_pattern {
+            fragment.shader_defs.push("LOW_BAND_PATTERN".into());
+        }
+
+        if key.bind_group_data.occlusion == BarOcclusion::XRay {
+            fragment.shader_defs.push("XRAY_OCCLUSION".into());
+        }
+
+        if key.bind_group_data.clamp_to_edge {
+            descriptor.vertex.shader_defs.push("EDGE_CLAMP".into());
+        }
+
+        if key.bind_group_data.arrow {
+            descriptor.vertex.shader_defs.push("IS_ARROW".into());
+            fragment.shader_defs.push("IS_ARROW".into());
         }
 
         if key.bind_group_data.world_space_offset {
@@ -87,6 +161,21 @@ impl Material for BarMaterial {
                 .push("WORLD_SPACE_OFFSET".into());
         }
 
+        match key.bind_group_data.billboard_mode {
+            BarBillboardMode::Spherical => {}
+            BarBillboardMode::Cylindrical => descriptor
+                .vertex
+                .shader_defs
+                .push("CYLINDRICAL_BILLBOARD".into()),
+            BarBillboardMode::Fixed => descriptor.vertex.shader_defs.push("FIXED_BILLBOARD".into()),
+        }
+
+        if key.bind_group_data.occlusion != BarOcclusion::DepthTested {
+            if let Some(depth_stencil) = descriptor.depth_stencil.as_mut() {
+                depth_stencil.depth_compare = CompareFunction::Always;
+            }
+        }
+
         descriptor.vertex.buffers = vec![vertex_layout];
         Ok(())
     }
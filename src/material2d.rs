@@ -8,7 +8,8 @@ use bevy::sprite_render::{Material2d, Material2dKey};
 
 use crate::constants::BAR_SHADER_2D_HANDLE;
 
-#[derive(Asset, AsBindGroup, Debug, Clone, Reflect)]
+/// Material of 2D bars and their icons
+#[derive(Asset, AsBindGroup, Debug, Clone, Default, Reflect)]
 #[bind_group_data(BarMaterial2dKey)]
 pub(crate) struct BarMaterial2d {
     #[uniform(0)]
@@ -26,20 +27,30 @@ pub(crate) struct BarMaterial2d {
     pub offset: Vec4,
     #[uniform(6)]
     pub border_color: LinearRgba,
+    #[texture(7)]
+    #[sampler(8)]
+    pub icon: Option<Handle<Image>>,
     pub vertical: bool,
+    pub low_band_pattern: bool,
+    pub world_space_offset: bool,
 }
 
+/// Pipeline specialization key of [`BarMaterial2d`]
 #[derive(Eq, PartialEq, Hash, Clone)]
 pub(crate) struct BarMaterial2dKey {
     vertical: bool,
-    border: bool,
+    world_space_offset: bool,
+    icon: bool,
+    low_band_pattern: bool,
 }
 
 impl From<&BarMaterial2d> for BarMaterial2dKey {
     fn from(material: &BarMaterial2d) -> Self {
         Self {
             vertical: material.vertical,
-            border: material.value_and_dimensions.w > 0.,
+            world_space_offset: material.world_space_offset,
+            icon: material.icon.is_some(),
+            low_band_pattern: material.low_band_pattern,
         }
     }
 }
@@ -68,8 +79,19 @@ impl Material2d for BarMaterial2d {
             fragment.shader_defs.push("IS_VERTICAL".into());
         }
 
-        if key.bind_group_data.border {
-            fragment.shader_defs.push("HAS_BORDER".into());
+        if key.bind_group_data.icon {
+            fragment.shader_defs.push("IS_ICON".into());
+        }
+
+        if key.bind_group_data.low_band_pattern {
+            fragment.shader_defs.push("LOW_BAND_PATTERN".into());
+        }
+
+        if key.bind_group_data.world_space_offset {
+            descriptor
+                .vertex
+                .shader_defs
+                .push("WORLD_SPACE_OFFSET".into());
         }
 
         descriptor.vertex.buffers = vec![vertex_layout];
@@ -2,21 +2,48 @@ use bevy::prelude::{Handle, Mesh, Mesh3d, Resource};
 use ordered_float::OrderedFloat;
 use std::collections::HashMap;
 
+/// Width, height and horizontal translation of a mesh
+type MeshKey = (OrderedFloat<f32>, OrderedFloat<f32>, OrderedFloat<f32>);
+
 #[derive(Resource, Default)]
-pub(crate) struct MeshHandles(pub HashMap<(OrderedFloat<f32>, OrderedFloat<f32>), Handle<Mesh>>);
+pub(crate) struct MeshHandles {
+    rectangles: HashMap<MeshKey, Handle<Mesh>>,
+    /// Arrow meshes of edge clamped bars by their size
+    #[cfg(feature = "3d")]
+    arrows: HashMap<OrderedFloat<f32>, Handle<Mesh>>,
+}
 
 impl MeshHandles {
     pub fn get(&self, width: f32, height: f32) -> Option<Mesh3d> {
-        self.0
-            .get(&(OrderedFloat(width), OrderedFloat(height)))
-            .cloned()
-            .map(Mesh3d)
+        self.get_translated(width, height, 0.).map(Mesh3d)
     }
 
     pub fn insert(&mut self, width: f32, height: f32, handle: Handle<Mesh>) -> Mesh3d {
-        self.0
-            .insert((OrderedFloat(width), OrderedFloat(height)), handle.clone());
+        self.insert_translated(width, height, 0., handle.clone());
 
         Mesh3d(handle)
     }
+
+    pub fn get_translated(&self, width: f32, height: f32, x: f32) -> Option<Handle<Mesh>> {
+        self.rectangles
+            .get(&(OrderedFloat(width), OrderedFloat(height), OrderedFloat(x)))
+            .cloned()
+    }
+
+    pub fn insert_translated(&mut self, width: f32, height: f32, x: f32, handle: Handle<Mesh>) {
+        self.rectangles.insert(
+            (OrderedFloat(width), OrderedFloat(height), OrderedFloat(x)),
+            handle,
+        );
+    }
+
+    #[cfg(feature = "3d")]
+    pub fn get_arrow(&self, size: f32) -> Option<Handle<Mesh>> {
+        self.arrows.get(&OrderedFloat(size)).cloned()
+    }
+
+    #[cfg(feature = "3d")]
+    pub fn insert_arrow(&mut self, size: f32, handle: Handle<Mesh>) {
+        self.arrows.insert(OrderedFloat(size), handle);
+    }
 }
@@ -0,0 +1,83 @@
+use std::marker::PhantomData;
+
+use bevy::prelude::*;
+
+use crate::configuration::Percentage;
+use crate::constants::{HIGH_THRESHOLD, LOW_THRESHOLD};
+
+/// Describes which of the tri-spectrum colors a value is displayed in.
+/// See [`ForegroundColor`](crate::prelude::ForegroundColor) for the thresholds between bands.
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BarBand {
+    High,
+    Moderate,
+    Low,
+}
+
+impl BarBand {
+    /// Returns the band a value between 0 and 1 falls into
+    pub fn from_value(value: f32) -> Self {
+        if value < LOW_THRESHOLD {
+            Self::Low
+        } else if value < HIGH_THRESHOLD {
+            Self::Moderate
+        } else {
+            Self::High
+        }
+    }
+}
+
+/// Message written when the value of a tracked component `T` moves into a different [`BarBand`]
+#[derive(Message, Debug, Clone)]
+pub struct BarThresholdCrossed<T: Percentage + Component + TypePath> {
+    /// The entity owning the tracked component
+    pub entity: Entity,
+    pub from: BarBand,
+    pub to: BarBand,
+    phantom_data: PhantomData<T>,
+}
+
+/// Message written when the value of a tracked component `T` drops to 0
+#[derive(Message, Debug, Clone)]
+pub struct BarEmptied<T: Percentage + Component + TypePath> {
+    /// The entity owning the tracked component
+    pub entity: Entity,
+    phantom_data: PhantomData<T>,
+}
+
+/// Message written when the value of a tracked component `T` reaches 1
+#[derive(Message, Debug, Clone)]
+pub struct BarFilled<T: Percentage + Component + TypePath> {
+    /// The entity owning the tracked component
+    pub entity: Entity,
+    phantom_data: PhantomData<T>,
+}
+
+impl<T: Percentage + Component + TypePath> BarThresholdCrossed<T> {
+    pub fn new(entity: Entity, from: BarBand, to: BarBand) -> Self {
+        Self {
+            entity,
+            from,
+            to,
+            phantom_data: PhantomData,
+        }
+    }
+}
+
+impl<T: Percentage + Component + TypePath> BarEmptied<T> {
+    pub fn new(entity: Entity) -> Self {
+        Self {
+            entity,
+            phantom_data: PhantomData,
+        }
+    }
+}
+
+impl<T: Percentage + Component + TypePath> BarFilled<T> {
+    pub fn new(entity: Entity) -> Self {
+        Self {
+            entity,
+            phantom_data: PhantomData,
+        }
+    }
+}
@@ -0,0 +1,238 @@
+use bevy::prelude::*;
+use bevy::transform::helper::TransformHelper;
+use bevy::ui::{DefaultUiCamera, UiTargetCamera};
+
+use crate::configuration::{BarRenderMode, BarSettings, Percentage};
+use crate::messages::BarBand;
+use crate::plugin::{Material, MaterialComponent, SettingsChanged};
+use crate::relationship::{Attached, HasAttached, HasBar};
+use crate::screen::{screen_node, ViewOffset};
+use crate::style::StyleResolver;
+
+/// Marks entities attached to a bar as its overlay
+pub(crate) struct OverlayAttachment;
+
+pub(crate) type OverlayOf = Attached<OverlayAttachment>;
+pub(crate) type HasOverlay = HasAttached<OverlayAttachment>;
+
+/// Root node of a bar drawn as a screen overlay, see [`BarRenderMode::ScreenOverlay`].
+/// Its only child is the node filled according to the value of the bar.
+#[derive(Component)]
+pub(crate) struct BarOverlay(ViewOffset);
+
+impl BarOverlay {
+    fn new<T: Percentage + Component + TypePath>(settings: &BarSettings<T>) -> Self {
+        Self(ViewOffset::new(settings))
+    }
+}
+
+pub(crate) fn spawn_overlay<T: Percentage + Component + TypePath>(
+    commands: &mut Commands,
+    bar: Entity,
+    settings: &BarSettings<T>,
+) {
+    let BarRenderMode::ScreenOverlay { camera } = settings.render_mode else {
+        return;
+    };
+
+    let fill = commands
+        .spawn((
+            Name::new(format!("{}OverlayFill", T::type_path())),
+            Node {
+                position_type: PositionType::Absolute,
+                ..default()
+            },
+            BackgroundColor::default(),
+        ))
+        .id();
+
+    let mut overlay = commands.spawn((
+        Name::new(format!("{}Overlay", T::type_path())),
+        screen_node(Node {
+            overflow: Overflow::clip(),
+            ..default()
+        }),
+        BackgroundColor::default(),
+        BorderColor::default(),
+        BarOverlay::new(settings),
+        OverlayOf::new(bar),
+    ));
+    overlay.add_child(fill);
+
+    if let Some(camera) = camera {
+        overlay.insert(UiTargetCamera(camera));
+    }
+}
+
+/// Spawns, updates and despawns the overlays of bars according to their render mode
+#[allow(clippy::type_complexity)]
+pub(crate) fn update_overlay_settings<T: Percentage + Component + TypePath>(
+    mut commands: Commands,
+    styles: StyleResolver,
+    parent_query: Query<(&HasBar<T>, &BarSettings<T>), SettingsChanged<T>>,
+    bar_query: Query<Option<&HasOverlay>>,
+) {
+    parent_query.iter().for_each(|(bar, settings)| {
+        let Ok(has_overlay) = bar_query.get(bar.bar()) else {
+            return;
+        };
+        let settings = styles.resolve(settings);
+
+        match (has_overlay, &settings.render_mode) {
+            (Some(has_overlay), BarRenderMode::ScreenOverlay { camera }) => {
+                let mut overlay = commands.entity(has_overlay.get());
+                overlay.insert(BarOverlay::new(&settings));
+                match camera {
+                    Some(camera) => overlay.insert(UiTargetCamera(*camera)),
+                    None => overlay.remove::<UiTargetCamera>(),
+                };
+            }
+            (Some(has_overlay), BarRenderMode::Billboard) => {
+                commands.entity(has_overlay.get()).despawn()
+            }
+            (None, BarRenderMode::ScreenOverlay { .. }) => {
+                spawn_overlay(&mut commands, bar.bar(), &settings)
+            }
+            (None, BarRenderMode::Billboard) => {}
+        }
+    });
+}
+
+/// Syncs the size, value and colors of overlays with the material of their bar, so that they follow the same
+/// settings, color scheme and accessibility palette as bars rendered in the world. The striped low band of
+/// [`BarAccessibility::low_band_pattern`](crate::prelude::BarAccessibility::low_band_pattern) is not drawn.
+#[allow(clippy::type_complexity)]
+pub(crate) fn sync_overlays(
+    materials: Res<Assets<Material>>,
+    bar_query: Query<&MaterialComponent>,
+    mut overlay_query: Query<
+        (
+            &OverlayOf,
+            &Children,
+            &mut Node,
+            &mut BackgroundColor,
+            &mut BorderColor,
+        ),
+        With<BarOverlay>,
+    >,
+    mut fill_query: Query<(&mut Node, &mut BackgroundColor), Without<BarOverlay>>,
+) {
+    for (overlay_of, children, mut node, mut background, mut border) in overlay_query.iter_mut() {
+        let Some(material) = bar_query
+            .get(overlay_of.bar())
+            .ok()
+            .and_then(|handle| materials.get(&handle.0))
+        else {
+            continue;
+        };
+
+        let [value, width, height, border_width] = material.value_and_dimensions.to_array();
+        let (width, height, border_width) = (
+            Val::Px(width),
+            Val::Px(height),
+            UiRect::all(Val::Px(border_width)),
+        );
+        if node.width != width || node.height != height || node.border != border_width {
+            node.width = width;
+            node.height = height;
+            node.border = border_width;
+        }
+        background.set_if_neq(BackgroundColor(material.background_color.into()));
+        border.set_if_neq(BorderColor::all(material.border_color));
+
+        let Some(Ok((mut fill_node, mut fill_color))) =
+            children.first().map(|fill| fill_query.get_mut(*fill))
+        else {
+            continue;
+        };
+
+        let value = value.clamp(0., 1.);
+        fill_node.set_if_neq(if material.vertical {
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(0.),
+                bottom: Val::Px(0.),
+                width: Val::Percent(100.),
+                height: Val::Percent(100. * value),
+                ..default()
+            }
+        } else {
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(0.),
+                top: Val::Px(0.),
+                width: Val::Percent(100. * value),
+                height: Val::Percent(100.),
+                ..default()
+            }
+        });
+
+        let color = match BarBand::from_value(value) {
+            BarBand::High => material.high_color,
+            BarBand::Moderate => material.moderate_color,
+            BarBand::Low => material.low_color,
+        };
+        fill_color.set_if_neq(BackgroundColor(color.into()));
+    }
+}
+
+/// Positions overlays centered on the projected position of their bar, as seen from their target camera or the
+/// default UI camera
+#[allow(clippy::type_complexity)]
+pub(crate) fn position_overlays(
+    ui_camera: DefaultUiCamera,
+    camera_query: Query<&Camera>,
+    transform_helper: TransformHelper,
+    bar_query: Query<&InheritedVisibility>,
+    mut overlay_query: Query<(
+        &OverlayOf,
+        &BarOverlay,
+        Option<&UiTargetCamera>,
+        &mut Node,
+        &mut Visibility,
+    )>,
+) {
+    for (overlay_of, overlay, target_camera, mut node, mut visibility) in overlay_query.iter_mut() {
+        let visible = bar_query
+            .get(overlay_of.bar())
+            .is_ok_and(|visibility| visibility.get());
+
+        let viewport_position = target_camera
+            .map(|target_camera| target_camera.0)
+            .or_else(|| ui_camera.get())
+            .filter(|_| visible)
+            .and_then(|camera_entity| {
+                let camera = camera_query.get(camera_entity).ok()?;
+                let camera_transform = transform_helper
+                    .compute_global_transform(camera_entity)
+                    .ok()?;
+                let bar_transform = transform_helper
+                    .compute_global_transform(overlay_of.bar())
+                    .ok()?;
+                let position =
+                    overlay
+                        .0
+                        .world_position(Vec2::ZERO, &bar_transform, &camera_transform);
+                camera.world_to_viewport(&camera_transform, position).ok()
+            });
+
+        let Some(viewport_position) = viewport_position else {
+            visibility.set_if_neq(Visibility::Hidden);
+            continue;
+        };
+
+        // sized in pixels by `sync_overlays`, so that overlays are centered before their first layout
+        let size = match (node.width, node.height) {
+            (Val::Px(width), Val::Px(height)) => Vec2::new(width, height),
+            _ => Vec2::ZERO,
+        };
+        let position = viewport_position - size / 2.;
+
+        let (left, top) = (Val::Px(position.x), Val::Px(position.y));
+        if node.left != left || node.top != top {
+            node.left = left;
+            node.top = top;
+        }
+        visibility.set_if_neq(Visibility::Inherited);
+    }
+}
@@ -0,0 +1,166 @@
+use std::collections::HashSet;
+
+use bevy::camera::primitives::Aabb;
+use bevy::math::{Mat3A, Vec3A};
+use bevy::prelude::*;
+
+use crate::configuration::{BarOrientation, BarSettings, Percentage};
+use crate::plugin::BarTransform;
+use crate::style::StyleResolver;
+
+/// Changes of the bounds smaller than this fraction of their size are ignored, so that animated
+/// entities don't update their bars, and create new meshes, every frame
+const PLACEMENT_TOLERANCE: f32 = 0.01;
+
+/// Bounds of an entity and its descendants, excluding its bars
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Bounds {
+    min: Vec3A,
+    max: Vec3A,
+}
+
+impl Bounds {
+    /// Returns the world space bounds of the given local bounds
+    fn transformed(aabb: &Aabb, transform: &GlobalTransform) -> Self {
+        let affine = transform.affine();
+        let matrix = affine.matrix3;
+        let abs_matrix = Mat3A::from_cols(
+            matrix.x_axis.abs(),
+            matrix.y_axis.abs(),
+            matrix.z_axis.abs(),
+        );
+
+        let center = affine.transform_point3a(aabb.center);
+        let half_extents = abs_matrix * aabb.half_extents;
+
+        Self {
+            min: center - half_extents,
+            max: center + half_extents,
+        }
+    }
+
+    fn union(self, other: Self) -> Self {
+        Self {
+            min: self.min.min(other.min),
+            max: self.max.max(other.max),
+        }
+    }
+
+    fn size(&self) -> Vec3A {
+        self.max - self.min
+    }
+}
+
+/// Returns the bounds of the owner and its descendants relative to the owner's translation, in the scale of its bars
+fn owner_bounds<T: Percentage + Component + TypePath>(
+    owner: Entity,
+    transform: &GlobalTransform,
+    settings: &BarSettings<T>,
+    bounds_query: &Query<(&Aabb, &GlobalTransform)>,
+    children_query: &Query<&Children>,
+    bar_query: &Query<(), With<BarTransform>>,
+) -> Option<Bounds> {
+    let mut bounds: Option<Bounds> = None;
+    let mut stack = vec![owner];
+    while let Some(entity) = stack.pop() {
+        if let Ok((aabb, aabb_transform)) = bounds_query.get(entity) {
+            let aabb_bounds = Bounds::transformed(aabb, aabb_transform);
+            bounds = Some(bounds.map_or(aabb_bounds, |bounds| bounds.union(aabb_bounds)));
+        }
+
+        if let Ok(children) = children_query.get(entity) {
+            stack.extend(children.iter().filter(|child| !bar_query.contains(*child)));
+        }
+    }
+
+    let (scale, _, translation) = transform.to_scale_rotation_translation();
+    let scale = if settings.ignore_parent_scale {
+        Vec3A::ONE
+    } else {
+        scale.into()
+    };
+
+    bounds.map(|bounds| Bounds {
+        min: (bounds.min - Vec3A::from(translation)) / scale,
+        max: (bounds.max - Vec3A::from(translation)) / scale,
+    })
+}
+
+/// Updates the offset and width of bars with [`BarSettings::auto_placement`] from the bounds of their owner.
+/// The bounds are only recomputed for owners whose settings changed, or with a changed entity in their hierarchy.
+#[allow(clippy::type_complexity)]
+pub(crate) fn update_auto_placement<T: Percentage + Component + TypePath>(
+    styles: StyleResolver,
+    mut owner_query: Query<(Entity, &GlobalTransform, &mut BarSettings<T>)>,
+    changed_query: Query<Entity, Or<(Changed<Aabb>, Changed<GlobalTransform>, Changed<Children>)>>,
+    parent_query: Query<&ChildOf>,
+    bounds_query: Query<(&Aabb, &GlobalTransform)>,
+    children_query: Query<&Children>,
+    bar_query: Query<(), With<BarTransform>>,
+) {
+    // changes below bars, such as of their icons, don't affect the bounds of the owner
+    let outdated: HashSet<Entity> = changed_query
+        .iter()
+        .flat_map(|entity| {
+            std::iter::once(entity)
+                .chain(parent_query.iter_ancestors(entity))
+                .take_while(|entity| !bar_query.contains(*entity))
+        })
+        .collect();
+
+    owner_query
+        .iter_mut()
+        .for_each(|(owner, transform, mut settings)| {
+            let Some(placement) = settings.auto_placement.clone() else {
+                return;
+            };
+            if !settings.is_changed() && !outdated.contains(&owner) {
+                return;
+            }
+            let Some(bounds) = owner_bounds(
+                owner,
+                transform,
+                &settings,
+                &bounds_query,
+                &children_query,
+                &bar_query,
+            ) else {
+                return;
+            };
+
+            let mut placed = settings.clone();
+            let orientation = styles.resolve(&placed).orientation.clone();
+            let size = bounds.size();
+            // distance from the owner to the edge of the bounds the bar is placed at
+            let edge = match orientation {
+                BarOrientation::Horizontal => bounds.max.y,
+                BarOrientation::Vertical => {
+                    let radius = bounds.min.abs().max(bounds.max.abs());
+                    radius.x.max(radius.z)
+                }
+            };
+
+            if let Some(factor) = placement.width_factor {
+                placed.width = factor
+                    * match orientation {
+                        BarOrientation::Horizontal => size.x.max(size.z),
+                        BarOrientation::Vertical => size.y,
+                    };
+            }
+
+            let resolved = styles.resolve(&placed);
+            let thickness = match orientation {
+                BarOrientation::Horizontal => resolved.normalized_height(),
+                BarOrientation::Vertical => resolved.normalized_width(),
+            };
+            placed.offset = edge + placement.margin + thickness / 2.;
+
+            let tolerance = size.max_element() * PLACEMENT_TOLERANCE;
+            if (placed.width - settings.width).abs() > tolerance
+                || (placed.offset - settings.offset).abs() > tolerance
+            {
+                settings.width = placed.width;
+                settings.offset = placed.offset;
+            }
+        });
+}
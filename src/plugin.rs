@@ -1,29 +1,63 @@
 use std::marker::PhantomData;
+use std::sync::Mutex;
 
 use bevy::asset::load_internal_asset;
+use bevy::camera::visibility::{RenderLayers, VisibilitySystems};
+use bevy::ecs::schedule::{BoxedCondition, InternedScheduleLabel, ScheduleLabel, SystemCondition};
 #[cfg(feature = "3d")]
 use bevy::light::{NotShadowCaster, NotShadowReceiver};
 use bevy::prelude::*;
-
-use crate::configuration::{ForegroundColor, Percentage};
+use bevy::transform::helper::TransformHelper;
+use bevy::transform::TransformSystems;
+
+use crate::anchor::update_anchors;
+use crate::configuration::{
+    BarAccessibility, BarAnchor, BarAutoPlacement, BarBillboardMode, BarBorder, BarEdgeClamp,
+    BarHeight, BarIcon, BarIconPlacement, BarOcclusion, BarPalette, BarRenderMode, ForegroundColor,
+    HealthBarGlobalSettings, Percentage,
+};
+#[cfg(feature = "labels")]
+use crate::configuration::{BarLabel, BarLabelContent, BarLabelPlacement};
+use crate::constants::BAR_COMMON_SHADER_HANDLE;
+use crate::icon::{spawn_icon, update_icon_settings, HasIcon};
+#[cfg(feature = "labels")]
+use crate::label::{spawn_label, update_label_settings, update_labels};
 use crate::mesh::MeshHandles;
-#[cfg(feature = "3d")]
-use crate::prelude::BarOffsetMode;
-use crate::prelude::{BarOrientation, BarSettings, ColorScheme};
+use crate::messages::{BarBand, BarEmptied, BarFilled, BarThresholdCrossed};
+#[cfg(feature = "overlay")]
+use crate::overlay::{position_overlays, spawn_overlay, sync_overlays, update_overlay_settings};
+use crate::placement::update_auto_placement;
+use crate::prelude::{BarOffsetMode, BarOrientation, BarSettings, ColorScheme};
+use crate::relationship::{BarOf, HasBar};
+#[cfg(feature = "serde")]
+use crate::style::BarStyleLoader;
+use crate::style::{update_styles, BarStyle, BarStyles, StyleResolver};
 
 // 3D-specific imports and type aliases
 #[cfg(feature = "3d")]
-use crate::constants::BAR_SHADER_HANDLE;
+use crate::arrow::{spawn_arrow, update_arrow_settings};
+#[cfg(feature = "3d")]
+use crate::camera::{
+    camera_texture, update_cameras, write_camera_texture, BarCameras, HealthBarCameraSettings,
+};
+#[cfg(feature = "3d")]
+use crate::constants::{BAR_CAMERAS_TEXTURE_HANDLE, BAR_SHADER_HANDLE};
 #[cfg(feature = "3d")]
 use crate::material::BarMaterial;
 #[cfg(feature = "3d")]
+use bevy::camera::visibility::NoFrustumCulling;
+#[cfg(feature = "3d")]
 use bevy::pbr::MaterialPlugin;
 #[cfg(feature = "3d")]
-type Material = BarMaterial;
+use bevy::render::extract_resource::ExtractResourcePlugin;
+#[cfg(feature = "3d")]
+use bevy::render::{Render, RenderApp, RenderSystems};
 #[cfg(feature = "3d")]
-type MeshComponent = Mesh3d;
+pub(crate) type Material = BarMaterial;
 #[cfg(feature = "3d")]
-type MaterialComponent = MeshMaterial3d<BarMaterial>;
+pub(crate) type MeshComponent = Mesh3d;
+#[cfg(feature = "3d")]
+pub(crate) type MaterialComponent = MeshMaterial3d<BarMaterial>;
 
 // 2D-specific imports and type aliases
 #[cfg(feature = "2d")]
@@ -33,22 +67,234 @@ use crate::material2d::BarMaterial2d;
 #[cfg(feature = "2d")]
 use bevy::sprite_render::Material2dPlugin;
 #[cfg(feature = "2d")]
-type Material = BarMaterial2d;
+pub(crate) type Material = BarMaterial2d;
 #[cfg(feature = "2d")]
-type MeshComponent = Mesh2d;
+pub(crate) type MeshComponent = Mesh2d;
 #[cfg(feature = "2d")]
-type MaterialComponent = MeshMaterial2d<BarMaterial2d>;
+pub(crate) type MaterialComponent = MeshMaterial2d<BarMaterial2d>;
+
+/// Changed to sync bars with the [`BarSettings`] of their owner when something the settings are resolved from has
+/// changed, such as the render layers of the owner, without marking the settings of the user as changed
+#[derive(Component)]
+pub(crate) struct ResyncBarSettings<T: Percentage + Component + TypePath>(PhantomData<T>);
+
+impl<T: Percentage + Component + TypePath> Default for ResyncBarSettings<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+/// Filter of owners whose bars have to be synced with their settings
+pub(crate) type SettingsChanged<T> = Or<(Changed<BarSettings<T>>, Changed<ResyncBarSettings<T>>)>;
+
+/// Marks bars hidden through [`HealthBarGlobalSettings::visible`], storing the visibility they are restored to
+#[derive(Component)]
+pub(crate) struct GloballyHidden(Visibility);
+
+/// Describes how a bar compensates for the transform of its owner. Inserted on the bars of all tracked component types.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub(crate) struct BarTransform {
+    /// Whether the bar rotates with its owner, see [`BarBillboardMode::Fixed`]
+    fixed: bool,
+    /// Whether the bar keeps its size regardless of the scale of its owner
+    ignore_parent_scale: bool,
+    /// Offset of bars using [`BarOffsetMode::WorldSpace`]. It is applied to the translation of the bar rather than in
+    /// the vertex shader, so that transparent bars are sorted by their actual position.
+    world_space_offset: Vec3,
+    /// Entity the bar follows instead of its parent, see [`BarSettings::anchor`]
+    pub(crate) anchor: Option<Entity>,
+}
 
+impl BarTransform {
+    fn new<T: Percentage + Component + TypePath>(settings: &BarSettings<T>) -> Self {
+        let world_space_offset = match settings.offset_mode {
+            BarOffsetMode::WorldSpace => settings.normalized_offset(),
+            BarOffsetMode::CameraSpace => Vec3::ZERO,
+        };
+
+        Self {
+            fixed: settings.billboard_mode == BarBillboardMode::Fixed,
+            ignore_parent_scale: settings.ignore_parent_scale,
+            world_space_offset,
+            // named anchors are resolved by `update_anchors`
+            anchor: match settings.anchor {
+                Some(BarAnchor::Entity(entity)) => Some(entity),
+                _ => None,
+            },
+        }
+    }
+
+    /// Returns the local transform of a bar attached to a parent with the given global transform.
+    /// The bar is placed at the translation of the anchor instead of the parent, if given. Its rotation and scale
+    /// still follow the parent, as bones are often scaled or rotated along with their armature.
+    fn local_transform(
+        &self,
+        parent: &GlobalTransform,
+        anchor: Option<&GlobalTransform>,
+    ) -> Transform {
+        let (parent_scale, parent_rotation, parent_translation) =
+            parent.to_scale_rotation_translation();
+        let origin = anchor.map_or(parent_translation, GlobalTransform::translation);
+
+        let scale = if self.ignore_parent_scale {
+            Vec3::ONE
+        } else {
+            parent_scale
+        };
+        let rotation = if self.fixed {
+            parent_rotation
+        } else {
+            Quat::IDENTITY
+        };
+        let translation = origin + rotation * (scale * self.world_space_offset);
+
+        GlobalTransform::from(Transform {
+            translation,
+            rotation,
+            scale,
+        })
+        .reparented_to(parent)
+    }
+}
+
+/// System sets of the plugin, to order systems relative to the bar systems
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum HealthBarSystems {
+    /// Spawns and despawns bars. Runs in the schedule of the plugin, [`Update`] by default.
+    Spawn,
+    /// Syncs bars with their tracked component and settings. Runs after [`HealthBarSystems::Spawn`].
+    Sync,
+    /// Compensates bars for the transform of their owner. Runs in [`PostUpdate`] before transform propagation.
+    Transform,
+}
+
+/// Registers the resources, types and systems shared by the bars of all tracked component types.
+/// Added once by the first [`HealthBarPlugin`].
+struct HealthBarCorePlugin;
+
+impl Plugin for HealthBarCorePlugin {
+    fn build(&self, app: &mut App) {
+        load_internal_asset!(
+            app,
+            BAR_COMMON_SHADER_HANDLE,
+            "../assets/shaders/bar_common.wgsl",
+            Shader::from_wgsl
+        );
+
+        // styles may have been initialized, or even loaded, before the first bar plugin is added
+        if !app.world().contains_resource::<Assets<BarStyle>>() {
+            app.init_asset::<BarStyle>();
+        }
+
+        app.init_resource::<BarStyles>()
+            .init_resource::<BarAccessibility>()
+            .register_type::<BarStyle>()
+            .register_type::<BarStyles>()
+            .register_type::<BarHeight>()
+            .register_type::<BarBorder>()
+            .register_type::<BarOrientation>()
+            .register_type::<BarOffsetMode>()
+            .register_type::<ForegroundColor>()
+            .register_type::<BarIcon>()
+            .register_type::<BarIconPlacement>()
+            .register_type::<BarPalette>()
+            .register_type::<BarBillboardMode>()
+            .register_type::<BarOcclusion>()
+            .register_type::<BarAutoPlacement>()
+            .register_type::<BarAnchor>()
+            .register_type::<BarEdgeClamp>()
+            .register_type::<BarRenderMode>()
+            .register_type::<BarAccessibility>()
+            .init_resource::<HealthBarGlobalSettings>()
+            .register_type::<HealthBarGlobalSettings>()
+            .add_systems(
+                PostUpdate,
+                update_global_visibility.before(VisibilitySystems::VisibilityPropagate),
+            )
+            .configure_sets(
+                PostUpdate,
+                HealthBarSystems::Transform.before(TransformSystems::Propagate),
+            )
+            .add_systems(
+                PostUpdate,
+                update_bar_transforms.in_set(HealthBarSystems::Transform),
+            );
+
+        #[cfg(feature = "labels")]
+        app.register_type::<BarLabel>()
+            .register_type::<BarLabelContent>()
+            .register_type::<BarLabelPlacement>();
+
+        #[cfg(feature = "overlay")]
+        app.add_systems(
+            PostUpdate,
+            (sync_overlays, position_overlays)
+                .chain()
+                .after(bevy::camera::CameraUpdateSystems)
+                .after(HealthBarSystems::Transform)
+                .before(bevy::ui::UiSystems::Layout),
+        );
+
+        #[cfg(feature = "serde")]
+        app.init_asset_loader::<BarStyleLoader>();
+    }
+}
+
+/// Plugin rendering bars for the component `T`. Add it once per tracked component type.
 pub struct HealthBarPlugin<T: Percentage + Component + TypePath> {
+    schedule: InternedScheduleLabel,
+    // taken when the plugin is built, as conditions can't be cloned
+    conditions: Mutex<Vec<BoxedCondition>>,
+    settings: Option<BarSettings<T>>,
+    color_scheme: Option<ColorScheme<T>>,
     phantom: PhantomData<T>,
 }
 
-impl<T: Percentage + Component + TypePath> Default for HealthBarPlugin<T> {
-    fn default() -> Self {
+impl<T: Percentage + Component + TypePath> HealthBarPlugin<T> {
+    pub fn new() -> Self {
         Self {
+            schedule: Update.intern(),
+            conditions: default(),
+            settings: None,
+            color_scheme: None,
             phantom: PhantomData,
         }
     }
+
+    /// Runs the systems spawning and syncing bars in the given schedule instead of [`Update`]
+    pub fn in_schedule(mut self, schedule: impl ScheduleLabel) -> Self {
+        self.schedule = schedule.intern();
+        self
+    }
+
+    /// Only runs the systems spawning and syncing bars of this type if the condition is met.
+    /// Can be called multiple times, all conditions have to be met.
+    pub fn run_if<M>(mut self, condition: impl SystemCondition<M>) -> Self {
+        self.conditions
+            .get_mut()
+            .unwrap()
+            .push(Box::new(IntoSystem::into_system(condition)));
+        self
+    }
+
+    /// Default [`BarSettings`] of entities with the component `T`.
+    /// Entities get a bar without inserting [`BarSettings`], which then overrides these defaults.
+    pub fn settings(mut self, settings: BarSettings<T>) -> Self {
+        self.settings = Some(settings);
+        self
+    }
+
+    /// Initial [`ColorScheme`] of bars tracking the component `T`
+    pub fn color_scheme(mut self, color_scheme: ColorScheme<T>) -> Self {
+        self.color_scheme = Some(color_scheme);
+        self
+    }
+}
+
+impl<T: Percentage + Component + TypePath> Default for HealthBarPlugin<T> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl<T: Percentage + Component + TypePath> Plugin for HealthBarPlugin<T> {
@@ -63,6 +309,32 @@ impl<T: Percentage + Component + TypePath> Plugin for HealthBarPlugin<T> {
                     "../assets/shaders/bar.wgsl",
                     Shader::from_wgsl
                 );
+
+                app.world_mut()
+                    .resource_mut::<Assets<Image>>()
+                    .insert(&BAR_CAMERAS_TEXTURE_HANDLE, camera_texture())
+                    .unwrap();
+                app.register_type::<HealthBarCameraSettings>()
+                    .init_resource::<BarCameras>()
+                    .add_plugins(ExtractResourcePlugin::<BarCameras>::default())
+                    .add_systems(
+                        PostUpdate,
+                        update_cameras.after(bevy::camera::CameraUpdateSystems),
+                    );
+                if let Some(render_app) = app.get_sub_app_mut(RenderApp) {
+                    render_app.add_systems(
+                        Render,
+                        write_camera_texture.in_set(RenderSystems::PrepareResources),
+                    );
+                }
+
+                #[cfg(feature = "labels")]
+                app.add_systems(
+                    PostUpdate,
+                    crate::label::position_labels
+                        .after(bevy::camera::CameraUpdateSystems)
+                        .before(bevy::ui::UiSystems::Layout),
+                );
             }
         }
 
@@ -79,37 +351,106 @@ impl<T: Percentage + Component + TypePath> Plugin for HealthBarPlugin<T> {
             }
         }
 
+        if !app.is_plugin_added::<HealthBarCorePlugin>() {
+            app.add_plugins(HealthBarCorePlugin);
+        }
+
+        if let Some(settings) = self.settings.clone() {
+            app.add_observer(move |add: On<Add, T>, mut commands: Commands| {
+                commands.entity(add.entity).insert_if_new(settings.clone());
+            });
+        }
+
+        if let Some(color_scheme) = self.color_scheme.clone() {
+            app.insert_resource(color_scheme);
+        }
+
         app.init_resource::<MeshHandles>()
             .init_resource::<ColorScheme<T>>()
             .register_type::<BarSettings<T>>()
-            .add_systems(PostUpdate, reset_rotation)
-            .add_systems(
-                Update,
-                (spawn::<T>, remove::<T>, update::<T>, update_settings::<T>),
+            .register_type::<ColorScheme<T>>()
+            .register_type::<BarOf<T>>()
+            .register_type::<HasBar<T>>()
+            .add_message::<BarThresholdCrossed<T>>()
+            .add_message::<BarEmptied<T>>()
+            .add_message::<BarFilled<T>>()
+            .configure_sets(
+                self.schedule,
+                (
+                    HealthBarSystems::Spawn,
+                    HealthBarSystems::Sync.run_if(not_paused),
+                )
+                    .chain(),
             );
-    }
-}
 
-#[derive(Component, Reflect)]
-struct WithBar<T: Percentage + Component>(Entity, #[reflect(ignore)] PhantomData<T>);
+        let sync_systems = (
+            update::<T>,
+            update_auto_placement::<T>.before(update_settings::<T>),
+            update_anchors::<T>.after(update_settings::<T>),
+            update_settings::<T>,
+            update_icon_settings::<T>,
+            update_styles::<T>.before(update_settings::<T>),
+            inherit_render_layers::<T>.before(update_settings::<T>),
+            update_color_scheme::<T>.run_if(
+                resource_changed::<ColorScheme<T>>.or(resource_changed::<BarAccessibility>),
+            ),
+        )
+            .in_set(HealthBarSystems::Sync);
 
-impl<T: Percentage + Component> WithBar<T> {
-    fn get(&self) -> Entity {
-        self.0
+        #[cfg(feature = "3d")]
+        let sync_systems = (
+            sync_systems,
+            update_arrow_settings::<T>
+                .after(update_settings::<T>)
+                .in_set(HealthBarSystems::Sync),
+        );
+
+        #[cfg(feature = "overlay")]
+        let sync_systems = (
+            sync_systems,
+            update_overlay_settings::<T>.in_set(HealthBarSystems::Sync),
+        );
+
+        #[cfg(feature = "labels")]
+        let sync_systems = (
+            sync_systems,
+            (update_labels::<T>, update_label_settings::<T>).in_set(HealthBarSystems::Sync),
+        );
+
+        let mut systems = (
+            (spawn::<T>, remove::<T>).in_set(HealthBarSystems::Spawn),
+            sync_systems,
+        )
+            .into_configs();
+        self.conditions
+            .lock()
+            .unwrap()
+            .drain(..)
+            .for_each(|condition| systems.run_if_dyn(condition));
+
+        app.add_systems(self.schedule, systems);
     }
 }
 
+/// Resolves the colors of a bar. Per-entity overrides take precedence over the [`BarAccessibility`] palette,
+/// which takes precedence over the [`ColorScheme`].
 fn resolve_colors<T: Percentage + Component + TypePath>(
     settings: &BarSettings<T>,
     color_scheme: &ColorScheme<T>,
+    accessibility: &BarAccessibility,
 ) -> (Color, Color, Color, Color) {
+    let (scheme_foreground, scheme_background) = match &accessibility.palette {
+        Some(palette) => (palette.foreground_color(), palette.background_color()),
+        None => (
+            color_scheme.foreground_color.clone(),
+            color_scheme.background_color,
+        ),
+    };
     let foreground = settings
         .foreground_color
         .as_ref()
-        .unwrap_or(&color_scheme.foreground_color);
-    let background = settings
-        .background_color
-        .unwrap_or(color_scheme.background_color);
+        .unwrap_or(&scheme_foreground);
+    let background = settings.background_color.unwrap_or(scheme_background);
 
     let (high, moderate, low) = match foreground {
         ForegroundColor::Static(color) => (*color, *color, *color),
@@ -123,136 +464,280 @@ fn resolve_colors<T: Percentage + Component + TypePath>(
     (background, high, moderate, low)
 }
 
-#[allow(clippy::type_complexity)]
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
 fn spawn<T: Percentage + Component + TypePath>(
     mut commands: Commands,
     mut materials: ResMut<Assets<Material>>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut mesh_handles: ResMut<MeshHandles>,
     color_scheme: Res<ColorScheme<T>>,
-    query: Query<(Entity, &T, &BarSettings<T>), Added<T>>,
+    accessibility: Res<BarAccessibility>,
+    styles: StyleResolver,
+    query: Query<(Entity, &T, &BarSettings<T>, Option<&RenderLayers>), Added<T>>,
 ) {
-    query.iter().for_each(|(entity, percentage, settings)| {
-        let width = settings.normalized_width();
-        let height = settings.normalized_height();
-
-        let mesh = mesh_handles.get(width, height).unwrap_or_else(|| {
-            mesh_handles.insert(
-                width,
-                height,
-                meshes.add(Mesh::from(Rectangle::new(width, height))),
-            )
-        });
+    query
+        .iter()
+        .for_each(|(entity, percentage, settings, owner_layers)| {
+            let settings = styles.resolve(settings);
+            let width = settings.normalized_width();
+            let height = settings.normalized_height();
+
+            let mesh = mesh_handles.get(width, height).unwrap_or_else(|| {
+                mesh_handles.insert(
+                    width,
+                    height,
+                    meshes.add(Mesh::from(Rectangle::new(width, height))),
+                )
+            });
 
-        let (background, high, moderate, low) = resolve_colors(settings, &color_scheme);
-
-        let material = materials.add(Material {
-            value_and_dimensions: (percentage.value(), width, height, settings.border.width).into(),
-            background_color: background.into(),
-            high_color: high.into(),
-            moderate_color: moderate.into(),
-            low_color: low.into(),
-            vertical: settings.orientation == BarOrientation::Vertical,
-            offset: settings.normalized_offset().extend(0.),
-            border_color: settings.border.color.into(),
-            #[cfg(feature = "3d")]
-            world_space_offset: settings.offset_mode == BarOffsetMode::WorldSpace,
-        });
+            let (background, high, moderate, low) =
+                resolve_colors(&settings, &color_scheme, &accessibility);
+
+            let material = materials.add(Material {
+                value_and_dimensions: (percentage.value(), width, height, settings.border.width)
+                    .into(),
+                background_color: background.into(),
+                high_color: high.into(),
+                moderate_color: moderate.into(),
+                low_color: low.into(),
+                vertical: settings.orientation == BarOrientation::Vertical,
+                low_band_pattern: accessibility.low_band_pattern,
+                offset: settings.normalized_offset().extend(0.),
+                border_color: settings.border.color.into(),
+                world_space_offset: settings.offset_mode == BarOffsetMode::WorldSpace,
+                #[cfg(feature = "3d")]
+                billboard_mode: settings.billboard_mode,
+                #[cfg(feature = "3d")]
+                occlusion: settings.occlusion,
+                #[cfg(feature = "3d")]
+                depth_bias: settings.depth_bias,
+                #[cfg(feature = "3d")]
+                edge_clamp: settings.edge_clamp_parameters(),
+                #[cfg(feature = "3d")]
+                clamp_to_edge: settings.edge_clamp.is_some(),
+                ..default()
+            });
 
-        #[cfg(feature = "3d")]
-        let health_bar = commands
-            .spawn((
-                Name::new(format!("{}Bar", T::type_path())),
+            commands
+                .entity(entity)
+                .insert(ResyncBarSettings::<T>::default());
+
+            let health_bar = commands
+                .spawn((
+                    Name::new(format!("{}Bar", T::type_path())),
+                    BarOf::<T>::new(entity),
+                    ChildOf(entity),
+                    Visibility::Inherited,
+                ))
+                .id();
+
+            #[cfg(feature = "3d")]
+            commands.entity(health_bar).insert((
                 Mesh3d(mesh.0),
                 MeshMaterial3d(material),
                 NotShadowCaster,
                 NotShadowReceiver,
-            ))
-            .id();
+            ));
 
-        #[cfg(feature = "2d")]
-        let health_bar = commands
-            .spawn((
-                Name::new(format!("{}Bar", T::type_path())),
-                Mesh2d(mesh.0),
-                MeshMaterial2d(material),
-            ))
-            .id();
+            // edge clamped bars are rendered while their entity is off-screen
+            #[cfg(feature = "3d")]
+            if settings.edge_clamp.is_some() {
+                commands.entity(health_bar).insert(NoFrustumCulling);
+            }
 
-        commands
-            .entity(entity)
-            .insert(WithBar(health_bar, PhantomData::<T>))
-            .add_child(health_bar);
-    });
+            #[cfg(feature = "2d")]
+            commands
+                .entity(health_bar)
+                .insert((Mesh2d(mesh.0), MeshMaterial2d(material)));
+
+            let render_layers = settings.render_layers(owner_layers);
+            if let Some(render_layers) = &render_layers {
+                commands.entity(health_bar).insert(render_layers.clone());
+            }
+
+            commands
+                .entity(health_bar)
+                .insert(BarTransform::new(&settings));
+
+            spawn_icon(
+                &mut commands,
+                &mut materials,
+                &mut meshes,
+                &mut mesh_handles,
+                health_bar,
+                &settings,
+                render_layers.as_ref(),
+            );
+
+            #[cfg(feature = "3d")]
+            spawn_arrow(
+                &mut commands,
+                &mut materials,
+                &mut meshes,
+                &mut mesh_handles,
+                health_bar,
+                &settings,
+                render_layers.as_ref(),
+            );
+
+            #[cfg(feature = "overlay")]
+            spawn_overlay(&mut commands, health_bar, &settings);
+
+            #[cfg(feature = "labels")]
+            spawn_label(&mut commands, health_bar, percentage, &settings);
+        });
 }
 
 fn update<T: Percentage + Component + TypePath>(
     mut materials: ResMut<Assets<Material>>,
-    parent_query: Query<(&WithBar<T>, &T), Changed<T>>,
+    mut threshold_crossed: MessageWriter<BarThresholdCrossed<T>>,
+    mut emptied: MessageWriter<BarEmptied<T>>,
+    mut filled: MessageWriter<BarFilled<T>>,
+    parent_query: Query<(Entity, &HasBar<T>, &T), Changed<T>>,
     bar_query: Query<&MaterialComponent>,
 ) {
-    parent_query.iter().for_each(|(bar, percentage)| {
-        let Ok(material_handle) = bar_query.get(bar.get()) else {
+    parent_query.iter().for_each(|(entity, bar, percentage)| {
+        let Ok(material_handle) = bar_query.get(bar.bar()) else {
             return;
         };
         let material = materials.get_mut(&material_handle.0).unwrap();
-        material.value_and_dimensions.x = percentage.value();
+        let previous = material.value_and_dimensions.x;
+        let value = percentage.value();
+        material.value_and_dimensions.x = value;
+
+        let (from, to) = (BarBand::from_value(previous), BarBand::from_value(value));
+        if from != to {
+            threshold_crossed.write(BarThresholdCrossed::new(entity, from, to));
+        }
+
+        if value <= 0. && previous > 0. {
+            emptied.write(BarEmptied::new(entity));
+        }
+
+        if value >= 1. && previous < 1. {
+            filled.write(BarFilled::new(entity));
+        }
     });
 }
 
-#[allow(clippy::type_complexity)]
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
 fn update_settings<T: Percentage + Component + TypePath>(
     mut commands: Commands,
     mut materials: ResMut<Assets<Material>>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut mesh_handles: ResMut<MeshHandles>,
     color_scheme: Res<ColorScheme<T>>,
-    parent_query: Query<(&WithBar<T>, &BarSettings<T>), Changed<BarSettings<T>>>,
-    bar_query: Query<(Entity, &MaterialComponent, &MeshComponent)>,
+    accessibility: Res<BarAccessibility>,
+    styles: StyleResolver,
+    parent_query: Query<(&HasBar<T>, &BarSettings<T>, Option<&RenderLayers>), SettingsChanged<T>>,
+    bar_query: Query<(Entity, &MaterialComponent, &MeshComponent, Option<&HasIcon>)>,
 ) {
-    parent_query.iter().for_each(|(bar, settings)| {
-        let Ok((entity, material_handle, mesh_handle)) = bar_query.get(bar.get()) else {
-            return;
-        };
-
-        let material = materials.get_mut(&material_handle.0).unwrap();
-        let offset = settings.normalized_offset().extend(0.);
-        let width = settings.normalized_width();
-        let height = settings.normalized_height();
-
-        let mesh_for_settings_dimensions = mesh_handles.get(width, height);
-        let mesh_changed =
-            mesh_for_settings_dimensions.as_ref().map(|m| &m.0) != Some(&mesh_handle.0);
-
-        if mesh_changed {
-            let new_mesh = mesh_for_settings_dimensions.unwrap_or_else(|| {
-                mesh_handles.insert(
-                    width,
-                    height,
-                    meshes.add(Mesh::from(Rectangle::new(width, height))),
-                )
-            });
+    parent_query
+        .iter()
+        .for_each(|(bar, settings, owner_layers)| {
+            let Ok((entity, material_handle, mesh_handle, has_icon)) = bar_query.get(bar.bar())
+            else {
+                return;
+            };
+
+            let settings = styles.resolve(settings);
+
+            // overlay bars, which may be configured by a style, are not rendered by any camera
+            let render_layers = settings.render_layers(owner_layers);
+            std::iter::once(entity)
+                .chain(has_icon.map(HasIcon::get))
+                .for_each(|entity| match &render_layers {
+                    Some(render_layers) => {
+                        commands.entity(entity).insert(render_layers.clone());
+                    }
+                    None => {
+                        commands.entity(entity).remove::<RenderLayers>();
+                    }
+                });
+
+            let material = materials.get_mut(&material_handle.0).unwrap();
+            let offset = settings.normalized_offset().extend(0.);
+            let width = settings.normalized_width();
+            let height = settings.normalized_height();
+
+            let mesh_for_settings_dimensions = mesh_handles.get(width, height);
+            let mesh_changed =
+                mesh_for_settings_dimensions.as_ref().map(|m| &m.0) != Some(&mesh_handle.0);
+
+            if mesh_changed {
+                let new_mesh = mesh_for_settings_dimensions.unwrap_or_else(|| {
+                    mesh_handles.insert(
+                        width,
+                        height,
+                        meshes.add(Mesh::from(Rectangle::new(width, height))),
+                    )
+                });
+
+                #[cfg(feature = "3d")]
+                commands.entity(entity).insert(Mesh3d(new_mesh.0));
+
+                #[cfg(feature = "2d")]
+                commands.entity(entity).insert(Mesh2d(new_mesh.0));
+
+                material.value_and_dimensions.y = width;
+                material.value_and_dimensions.z = height;
+            }
 
+            material.offset = offset;
+            material.border_color = settings.border.color.into();
+            material.value_and_dimensions.w = settings.border.width;
+            material.vertical = settings.orientation == BarOrientation::Vertical;
+            material.low_band_pattern = accessibility.low_band_pattern;
+            material.world_space_offset = settings.offset_mode == BarOffsetMode::WorldSpace;
             #[cfg(feature = "3d")]
-            commands.entity(entity).insert(Mesh3d(new_mesh.0));
+            {
+                material.billboard_mode = settings.billboard_mode;
+                material.occlusion = settings.occlusion;
+                material.depth_bias = settings.depth_bias;
+                material.edge_clamp = settings.edge_clamp_parameters();
+                material.clamp_to_edge = settings.edge_clamp.is_some();
+
+                match settings.edge_clamp {
+                    Some(_) => commands.entity(entity).insert(NoFrustumCulling),
+                    None => commands.entity(entity).remove::<NoFrustumCulling>(),
+                };
+            }
 
-            #[cfg(feature = "2d")]
-            commands.entity(entity).insert(Mesh2d(new_mesh.0));
+            commands.entity(entity).insert(BarTransform::new(&settings));
 
-            material.value_and_dimensions.y = width;
-            material.value_and_dimensions.z = height;
-        }
+            let (background, high, moderate, low) =
+                resolve_colors(&settings, &color_scheme, &accessibility);
+            material.background_color = background.into();
+            material.high_color = high.into();
+            material.moderate_color = moderate.into();
+            material.low_color = low.into();
+        });
+}
 
-        material.offset = offset;
-        material.border_color = settings.border.color.into();
-        material.value_and_dimensions.w = settings.border.width;
-        material.vertical = settings.orientation == BarOrientation::Vertical;
-        #[cfg(feature = "3d")]
-        {
-            material.world_space_offset = settings.offset_mode == BarOffsetMode::WorldSpace;
+/// Re-applies the colors of bars when the [`ColorScheme`] or [`BarAccessibility`] changes at runtime.
+/// The colors of bars overriding both fore- and background color per entity are skipped.
+fn update_color_scheme<T: Percentage + Component + TypePath>(
+    mut materials: ResMut<Assets<Material>>,
+    color_scheme: Res<ColorScheme<T>>,
+    accessibility: Res<BarAccessibility>,
+    styles: StyleResolver,
+    parent_query: Query<(&HasBar<T>, &BarSettings<T>)>,
+    bar_query: Query<&MaterialComponent>,
+) {
+    parent_query.iter().for_each(|(bar, settings)| {
+        let Ok(material_handle) = bar_query.get(bar.bar()) else {
+            return;
+        };
+        let material = materials.get_mut(&material_handle.0).unwrap();
+        material.low_band_pattern = accessibility.low_band_pattern;
+
+        let settings = styles.resolve(settings);
+        if settings.foreground_color.is_some() && settings.background_color.is_some() {
+            return;
         }
 
-        let (background, high, moderate, low) = resolve_colors(settings, &color_scheme);
+        let (background, high, moderate, low) =
+            resolve_colors(&settings, &color_scheme, &accessibility);
         material.background_color = background.into();
         material.high_color = high.into();
         material.moderate_color = moderate.into();
@@ -260,15 +745,50 @@ fn update_settings<T: Percentage + Component + TypePath>(
     });
 }
 
-fn remove<T: Percentage + Component>(
+/// Resyncs bars inheriting the render layers of their owner when those are changed or removed, so that the
+/// render layers of the bar are updated
+#[allow(clippy::type_complexity)]
+fn inherit_render_layers<T: Percentage + Component + TypePath>(
+    mut removals: RemovedComponents<RenderLayers>,
+    mut parent_query: Query<(
+        Entity,
+        &BarSettings<T>,
+        Option<Ref<RenderLayers>>,
+        &mut ResyncBarSettings<T>,
+    )>,
+) {
+    let removed: Vec<_> = removals.read().collect();
+
+    parent_query
+        .iter_mut()
+        .for_each(|(entity, settings, render_layers, mut resync)| {
+            if settings.render_layers.is_some() {
+                return;
+            }
+
+            let changed = match render_layers {
+                Some(render_layers) => render_layers.is_changed(),
+                None => removed.contains(&entity),
+            };
+
+            if changed {
+                resync.set_changed();
+            }
+        });
+}
+
+fn remove<T: Percentage + Component + TypePath>(
     mut commands: Commands,
     mut removals: RemovedComponents<T>,
-    parent_query: Query<&WithBar<T>>,
+    parent_query: Query<&HasBar<T>>,
 ) {
     removals.read().for_each(|entity| {
-        let Ok(&WithBar(bar_entity, _)) = parent_query.get(entity) else {
+        let Ok(has_bar) = parent_query.get(entity) else {
             return;
         };
+        let bar_entity = has_bar.bar();
+
+        commands.entity(entity).try_remove::<ResyncBarSettings<T>>();
 
         if commands.get_entity(bar_entity).is_err() {
             return;
@@ -278,13 +798,79 @@ fn remove<T: Percentage + Component>(
     });
 }
 
-fn reset_rotation(
-    mut bar_query: Query<(&ChildOf, &mut Transform), With<MaterialComponent>>,
-    q_transform: Query<&Transform, Without<MaterialComponent>>,
+/// Compensates bars for the transform of their owner. The global transform of the owner is computed from its
+/// ancestors, so that nested hierarchies are taken into account before transforms are propagated.
+fn update_bar_transforms(
+    mut transforms: ParamSet<(TransformHelper, Query<&mut Transform, With<BarTransform>>)>,
+    bar_query: Query<(Entity, &ChildOf, &BarTransform)>,
 ) {
-    for (child_of, mut transform) in bar_query.iter_mut() {
-        if let Ok(parent_transform) = q_transform.get(child_of.parent()) {
-            transform.rotation = parent_transform.rotation.inverse();
-        }
+    let helper = transforms.p0();
+    let local_transforms: Vec<_> = bar_query
+        .iter()
+        .filter_map(|(entity, child_of, bar_transform)| {
+            let parent = helper.compute_global_transform(child_of.parent()).ok()?;
+            let anchor = bar_transform
+                .anchor
+                .and_then(|anchor| helper.compute_global_transform(anchor).ok());
+            Some((
+                entity,
+                bar_transform.local_transform(&parent, anchor.as_ref()),
+            ))
+        })
+        .collect();
+
+    let mut bar_transforms = transforms.p1();
+    local_transforms
+        .into_iter()
+        .for_each(|(entity, local_transform)| {
+            let Ok(mut transform) = bar_transforms.get_mut(entity) else {
+                return;
+            };
+            transform.set_if_neq(local_transform);
+        });
+}
+
+fn not_paused(global_settings: Res<HealthBarGlobalSettings>) -> bool {
+    !global_settings.paused
+}
+
+/// Hides or shows the bars of all tracked component types according to [`HealthBarGlobalSettings::visible`].
+/// Bars that are hidden already are left untouched, so that they stay hidden when shown again.
+#[allow(clippy::type_complexity)]
+fn update_global_visibility(
+    mut commands: Commands,
+    global_settings: Res<HealthBarGlobalSettings>,
+    mut visible_query: Query<
+        (Entity, &mut Visibility),
+        (With<BarTransform>, Without<GloballyHidden>),
+    >,
+    mut hidden_query: Query<(Entity, &mut Visibility, &mut GloballyHidden), With<BarTransform>>,
+) {
+    hidden_query
+        .iter_mut()
+        .for_each(|(entity, mut visibility, mut hidden)| {
+            // visibilities changed while hidden are kept, or restored later if still hidden
+            if visibility.is_changed() {
+                hidden.0 = *visibility;
+            }
+
+            if global_settings.visible {
+                *visibility = hidden.0;
+                commands.entity(entity).remove::<GloballyHidden>();
+            } else {
+                visibility.set_if_neq(Visibility::Hidden);
+            }
+        });
+
+    if global_settings.visible {
+        return;
     }
+
+    visible_query
+        .iter_mut()
+        .filter(|(_, visibility)| **visibility != Visibility::Hidden)
+        .for_each(|(entity, mut visibility)| {
+            commands.entity(entity).insert(GloballyHidden(*visibility));
+            *visibility = Visibility::Hidden;
+        });
 }
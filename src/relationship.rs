@@ -0,0 +1,149 @@
+use std::marker::PhantomData;
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+use crate::configuration::Percentage;
+
+/// Relationship component inserted on every spawned bar entity.
+/// Points to the entity owning the component `T` the bar is tracking.
+#[derive(Component, Debug, Reflect)]
+#[relationship(relationship_target = HasBar<T>)]
+pub struct BarOf<T: Percentage + Component + TypePath> {
+    #[relationship]
+    owner: Entity,
+    #[reflect(ignore)]
+    phantom_data: PhantomData<T>,
+}
+
+impl<T: Percentage + Component + TypePath> BarOf<T> {
+    pub fn new(owner: Entity) -> Self {
+        Self {
+            owner,
+            phantom_data: PhantomData,
+        }
+    }
+
+    /// The entity owning the tracked component
+    pub fn owner(&self) -> Entity {
+        self.owner
+    }
+}
+
+/// Relationship target inserted on entities with a bar for the component `T`.
+/// Despawning the owner despawns its bar.
+#[derive(Component, Debug, Reflect)]
+#[relationship_target(relationship = BarOf<T>, linked_spawn)]
+pub struct HasBar<T: Percentage + Component + TypePath> {
+    #[relationship]
+    bar: Entity,
+    #[reflect(ignore)]
+    phantom_data: PhantomData<T>,
+}
+
+impl<T: Percentage + Component + TypePath> HasBar<T> {
+    /// The bar entity tracking the component `T`
+    pub fn bar(&self) -> Entity {
+        self.bar
+    }
+}
+
+/// System parameter to look up bars of type `T` from their owners and vice versa.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_bar3d::prelude::{Bars, Percentage};
+///
+/// #[derive(Component, Reflect)]
+/// struct Health(f32);
+///
+/// impl Percentage for Health {
+///     fn value(&self) -> f32 {
+///         self.0
+///     }
+/// }
+///
+/// #[derive(Component)]
+/// struct Player;
+///
+/// fn hide_player_bar(
+///     mut commands: Commands,
+///     bars: Bars<Health>,
+///     player: Single<Entity, With<Player>>,
+/// ) {
+///     if let Some(bar) = bars.bar(*player) {
+///         commands.entity(bar).insert(Visibility::Hidden);
+///     }
+/// }
+/// ```
+#[derive(SystemParam)]
+pub struct Bars<'w, 's, T: Percentage + Component + TypePath> {
+    owners: Query<'w, 's, (Entity, &'static HasBar<T>)>,
+    bars: Query<'w, 's, &'static BarOf<T>>,
+}
+
+impl<T: Percentage + Component + TypePath> Bars<'_, '_, T> {
+    /// Returns the bar entity tracking `T` on the given owner, if there is one
+    pub fn bar(&self, owner: Entity) -> Option<Entity> {
+        self.owners
+            .get(owner)
+            .ok()
+            .map(|(_, has_bar)| has_bar.bar())
+    }
+
+    /// Returns the entity owning the component tracked by the given bar, if it is a bar of type `T`
+    pub fn owner(&self, bar: Entity) -> Option<Entity> {
+        self.bars.get(bar).ok().map(BarOf::owner)
+    }
+
+    /// Iterates over all `(owner, bar)` pairs for the component type `T`
+    pub fn iter(&self) -> impl Iterator<Item = (Entity, Entity)> + '_ {
+        self.owners
+            .iter()
+            .map(|(owner, has_bar)| (owner, has_bar.bar()))
+    }
+}
+
+/// Relationship component of entities attached to a bar, such as its icon or label, where `K` marks the kind of
+/// attachment
+#[derive(Component)]
+#[relationship(relationship_target = HasAttached<K>)]
+pub(crate) struct Attached<K: Send + Sync + 'static> {
+    #[relationship]
+    bar: Entity,
+    phantom_data: PhantomData<K>,
+}
+
+impl<K: Send + Sync + 'static> Attached<K> {
+    pub fn new(bar: Entity) -> Self {
+        Self {
+            bar,
+            phantom_data: PhantomData,
+        }
+    }
+
+    /// The bar the entity is attached to
+    #[cfg(any(all(feature = "3d", feature = "labels"), feature = "overlay"))]
+    pub fn bar(&self) -> Entity {
+        self.bar
+    }
+}
+
+/// Relationship target inserted on bars with an attachment of kind `K`.
+/// Despawning the bar despawns the attachment.
+#[derive(Component)]
+#[relationship_target(relationship = Attached<K>, linked_spawn)]
+pub(crate) struct HasAttached<K: Send + Sync + 'static> {
+    #[relationship]
+    attached: Entity,
+    phantom_data: PhantomData<K>,
+}
+
+impl<K: Send + Sync + 'static> HasAttached<K> {
+    /// The attached entity
+    pub fn get(&self) -> Entity {
+        self.attached
+    }
+}
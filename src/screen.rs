@@ -0,0 +1,47 @@
+use bevy::prelude::*;
+
+use crate::configuration::{BarOffsetMode, BarSettings, Percentage};
+
+/// Offset of a bar along the camera's right and up vectors, shared by the labels and overlays placed over it
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct ViewOffset(Vec2);
+
+impl ViewOffset {
+    pub fn new<T: Percentage + Component + TypePath>(settings: &BarSettings<T>) -> Self {
+        // world space offsets are part of the bar's transform already
+        Self(match settings.offset_mode {
+            BarOffsetMode::WorldSpace => Vec2::ZERO,
+            BarOffsetMode::CameraSpace => settings.normalized_offset().truncate(),
+        })
+    }
+
+    pub fn get(&self) -> Vec2 {
+        self.0
+    }
+
+    /// Returns the world position of a point, given relative to the bar center along the camera's right and up
+    /// vectors, for a bar seen from the given camera
+    #[cfg(any(feature = "3d", feature = "overlay"))]
+    pub fn world_position(
+        &self,
+        point: Vec2,
+        bar: &GlobalTransform,
+        camera: &GlobalTransform,
+    ) -> Vec3 {
+        let point = point + self.get();
+        let (right, up) = (camera.right().as_vec3(), camera.up().as_vec3());
+        bar.transform_point(right * point.x + up * point.y)
+    }
+}
+
+/// Returns the absolutely positioned node of a UI entity placed over a bar, hidden until it is first positioned
+#[cfg(any(feature = "3d", feature = "overlay"))]
+pub(crate) fn screen_node(node: Node) -> (Node, Visibility) {
+    (
+        Node {
+            position_type: PositionType::Absolute,
+            ..node
+        },
+        Visibility::Hidden,
+    )
+}
@@ -0,0 +1,269 @@
+use std::borrow::Cow;
+
+#[cfg(feature = "serde")]
+use bevy::asset::{io::Reader, AssetLoader, LoadContext};
+use bevy::ecs::system::SystemParam;
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::configuration::{
+    BarBillboardMode, BarBorder, BarEdgeClamp, BarHeight, BarOcclusion, BarOffsetMode,
+    BarOrientation, BarRenderMode, BarSettings, ForegroundColor, Percentage,
+};
+use crate::plugin::ResyncBarSettings;
+
+/// Partial bar configuration of dimensions, border, colors and effects that can be shared between bars and loaded
+/// from `.bar.ron` files when the `serde` feature is enabled. Fields that are set take precedence over the
+/// corresponding fields of the [`BarSettings`] referencing the style.
+///
+/// ```ron
+/// (
+///     width: 1.5,
+///     height: Static(0.15),
+///     border: (width: 0.02, color: Srgba((red: 1.0, green: 0.8, blue: 0.0, alpha: 1.0))),
+///     foreground_color: Static(Srgba((red: 0.6, green: 0.0, blue: 0.8, alpha: 0.95))),
+///     occlusion: AlwaysOnTop,
+///     edge_clamp: (margin: 24.0),
+/// )
+/// ```
+#[derive(Asset, Debug, Clone, Default, Reflect)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(default))]
+#[reflect(Default)]
+pub struct BarStyle {
+    pub width: Option<f32>,
+    pub offset: Option<f32>,
+    pub height: Option<BarHeight>,
+    pub border: Option<BarBorder>,
+    pub orientation: Option<BarOrientation>,
+    pub offset_mode: Option<BarOffsetMode>,
+    pub foreground_color: Option<ForegroundColor>,
+    pub background_color: Option<Color>,
+    pub billboard_mode: Option<BarBillboardMode>,
+    pub occlusion: Option<BarOcclusion>,
+    pub depth_bias: Option<f32>,
+    pub edge_clamp: Option<BarEdgeClamp>,
+    pub render_mode: Option<BarRenderMode>,
+}
+
+impl BarStyle {
+    /// Returns true if none of the fields are set
+    pub fn is_empty(&self) -> bool {
+        self.width.is_none()
+            && self.offset.is_none()
+            && self.height.is_none()
+            && self.border.is_none()
+            && self.orientation.is_none()
+            && self.offset_mode.is_none()
+            && self.foreground_color.is_none()
+            && self.background_color.is_none()
+            && self.billboard_mode.is_none()
+            && self.occlusion.is_none()
+            && self.depth_bias.is_none()
+            && self.edge_clamp.is_none()
+            && self.render_mode.is_none()
+    }
+
+    fn apply<T: Percentage + Component + TypePath>(&self, settings: &mut BarSettings<T>) {
+        if let Some(width) = self.width {
+            settings.width = width;
+        }
+        if let Some(offset) = self.offset {
+            settings.offset = offset;
+        }
+        if let Some(height) = &self.height {
+            settings.height = height.clone();
+        }
+        if let Some(border) = &self.border {
+            settings.border = border.clone();
+        }
+        if let Some(orientation) = &self.orientation {
+            settings.orientation = orientation.clone();
+        }
+        if let Some(offset_mode) = &self.offset_mode {
+            settings.offset_mode = offset_mode.clone();
+        }
+        if let Some(foreground_color) = &self.foreground_color {
+            settings.foreground_color = Some(foreground_color.clone());
+        }
+        if let Some(background_color) = self.background_color {
+            settings.background_color = Some(background_color);
+        }
+        if let Some(billboard_mode) = self.billboard_mode {
+            settings.billboard_mode = billboard_mode;
+        }
+        if let Some(occlusion) = self.occlusion {
+            settings.occlusion = occlusion;
+        }
+        if let Some(depth_bias) = self.depth_bias {
+            settings.depth_bias = depth_bias;
+        }
+        if let Some(edge_clamp) = &self.edge_clamp {
+            settings.edge_clamp = Some(edge_clamp.clone());
+        }
+        if let Some(render_mode) = &self.render_mode {
+            settings.render_mode = render_mode.clone();
+        }
+    }
+}
+
+/// Registry of named [`BarStyle`] presets, which can be referenced from [`BarSettings::preset`] by any tracked
+/// component type.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_bar3d::prelude::{BarBorder, BarStyle, BarStyles};
+///
+/// fn setup(mut styles: ResMut<Assets<BarStyle>>, mut presets: ResMut<BarStyles>) {
+///     let boss = styles.add(BarStyle {
+///         width: Some(2.),
+///         border: Some(BarBorder::new(0.05)),
+///         ..default()
+///     });
+///     presets.insert("boss", boss);
+/// }
+/// ```
+#[derive(Resource, Debug, Clone, Default, Reflect)]
+#[reflect(Resource, Default)]
+pub struct BarStyles(HashMap<String, Handle<BarStyle>>);
+
+impl BarStyles {
+    pub fn insert(&mut self, name: impl Into<String>, style: Handle<BarStyle>) {
+        self.0.insert(name.into(), style);
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Handle<BarStyle>> {
+        self.0.get(name)
+    }
+
+    pub fn remove(&mut self, name: &str) -> Option<Handle<BarStyle>> {
+        self.0.remove(name)
+    }
+}
+
+impl<T: Percentage + Component + TypePath> BarSettings<T> {
+    /// Returns the settings with the referenced preset, [`BarStyle`] and overrides applied, in that order.
+    /// Presets and styles that are not loaded yet are skipped.
+    pub fn resolve(&self, styles: &Assets<BarStyle>, presets: &BarStyles) -> Cow<'_, Self> {
+        let preset = self
+            .preset
+            .as_deref()
+            .and_then(|name| presets.get(name))
+            .and_then(|handle| styles.get(handle));
+        let style = self.style.as_ref().and_then(|handle| styles.get(handle));
+
+        if preset.is_none() && style.is_none() && self.overrides.is_empty() {
+            return Cow::Borrowed(self);
+        }
+
+        let mut settings = self.clone();
+        [preset, style, Some(&self.overrides)]
+            .into_iter()
+            .flatten()
+            .for_each(|style| style.apply(&mut settings));
+
+        // placed fields take precedence, as they are derived from the bounds of the entity
+        if let Some(placement) = &self.auto_placement {
+            settings.offset = self.offset;
+            if placement.width_factor.is_some() {
+                settings.width = self.width;
+            }
+        }
+        Cow::Owned(settings)
+    }
+}
+
+/// Resolves [`BarSettings`] against the registered styles and presets
+#[derive(SystemParam)]
+pub(crate) struct StyleResolver<'w> {
+    styles: Res<'w, Assets<BarStyle>>,
+    presets: Res<'w, BarStyles>,
+}
+
+impl StyleResolver<'_> {
+    pub fn resolve<'a, T: Percentage + Component + TypePath>(
+        &self,
+        settings: &'a BarSettings<T>,
+    ) -> Cow<'a, BarSettings<T>> {
+        settings.resolve(&self.styles, &self.presets)
+    }
+}
+
+/// Loads [`BarStyle`] assets from `.bar.ron` files.
+/// Optional fields may be written without wrapping them in `Some`.
+#[cfg(feature = "serde")]
+#[derive(Default, TypePath)]
+pub struct BarStyleLoader;
+
+#[cfg(feature = "serde")]
+#[derive(Debug, thiserror::Error)]
+pub enum BarStyleLoaderError {
+    #[error("Could not read bar style: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Could not parse bar style: {0}")]
+    Ron(#[from] ron::error::SpannedError),
+}
+
+#[cfg(feature = "serde")]
+impl AssetLoader for BarStyleLoader {
+    type Asset = BarStyle;
+    type Settings = ();
+    type Error = BarStyleLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+
+        let style = ron::Options::default()
+            .with_default_extension(ron::extensions::Extensions::IMPLICIT_SOME)
+            .from_bytes(&bytes)?;
+
+        Ok(style)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["bar.ron"]
+    }
+}
+
+/// Resyncs bars referencing a style when the style is loaded or modified, or when the presets are changed,
+/// so that the style is applied to the bar
+pub(crate) fn update_styles<T: Percentage + Component + TypePath>(
+    mut events: MessageReader<AssetEvent<BarStyle>>,
+    presets: Res<BarStyles>,
+    mut parent_query: Query<(&BarSettings<T>, &mut ResyncBarSettings<T>)>,
+) {
+    let changed: Vec<_> = events
+        .read()
+        .filter_map(|event| match event {
+            AssetEvent::LoadedWithDependencies { id } | AssetEvent::Modified { id } => Some(*id),
+            _ => None,
+        })
+        .collect();
+
+    if changed.is_empty() && !presets.is_changed() {
+        return;
+    }
+
+    parent_query.iter_mut().for_each(|(settings, mut resync)| {
+        let preset = settings.preset.as_deref().map(|name| presets.get(name));
+        let uses_changed_style = settings
+            .style
+            .iter()
+            .chain(preset.flatten())
+            .any(|handle| changed.contains(&handle.id()));
+        let uses_changed_preset = preset.is_some() && presets.is_changed();
+
+        if uses_changed_style || uses_changed_preset {
+            resync.set_changed();
+        }
+    });
+}
@@ -0,0 +1,99 @@
+//! Read-only access to the materials of bars for the integration tests of this crate. Not part of the public API.
+
+use bevy::asset::AssetEvent;
+use bevy::ecs::message::MessageCursor;
+use bevy::prelude::*;
+
+#[cfg(feature = "3d")]
+use crate::configuration::{BarBillboardMode, BarOcclusion};
+use crate::plugin::{Material, MaterialComponent};
+
+/// Snapshot of the values of the material of a bar, arrow or icon
+#[derive(Debug, Clone, PartialEq)]
+pub struct MaterialValues {
+    /// Value, width, height and border width
+    pub value_and_dimensions: Vec4,
+    pub background_color: LinearRgba,
+    pub high_color: LinearRgba,
+    pub moderate_color: LinearRgba,
+    pub low_color: LinearRgba,
+    pub offset: Vec4,
+    pub border_color: LinearRgba,
+    pub icon: Option<Handle<Image>>,
+    pub vertical: bool,
+    pub low_band_pattern: bool,
+    pub world_space_offset: bool,
+    /// Margin, arrow size, bar width and bar height
+    #[cfg(feature = "3d")]
+    pub edge_clamp: Vec4,
+    #[cfg(feature = "3d")]
+    pub clamp_to_edge: bool,
+    #[cfg(feature = "3d")]
+    pub arrow: bool,
+    #[cfg(feature = "3d")]
+    pub depth_bias: f32,
+    #[cfg(feature = "3d")]
+    pub billboard_mode: BarBillboardMode,
+    #[cfg(feature = "3d")]
+    pub occlusion: BarOcclusion,
+}
+
+impl From<&Material> for MaterialValues {
+    fn from(material: &Material) -> Self {
+        Self {
+            value_and_dimensions: material.value_and_dimensions,
+            background_color: material.background_color,
+            high_color: material.high_color,
+            moderate_color: material.moderate_color,
+            low_color: material.low_color,
+            offset: material.offset,
+            border_color: material.border_color,
+            icon: material.icon.clone(),
+            vertical: material.vertical,
+            low_band_pattern: material.low_band_pattern,
+            world_space_offset: material.world_space_offset,
+            #[cfg(feature = "3d")]
+            edge_clamp: material.edge_clamp,
+            #[cfg(feature = "3d")]
+            clamp_to_edge: material.clamp_to_edge,
+            #[cfg(feature = "3d")]
+            arrow: material.arrow,
+            #[cfg(feature = "3d")]
+            depth_bias: material.depth_bias,
+            #[cfg(feature = "3d")]
+            billboard_mode: material.billboard_mode,
+            #[cfg(feature = "3d")]
+            occlusion: material.occlusion,
+        }
+    }
+}
+
+/// Returns the values of the material of an entity, if it has one
+pub fn material_values(world: &World, entity: Entity) -> Option<MaterialValues> {
+    let handle = world.get::<MaterialComponent>(entity)?;
+    world
+        .resource::<Assets<Material>>()
+        .get(&handle.0)
+        .map(MaterialValues::from)
+}
+
+/// Counts the modifications of materials since it was created
+pub struct MaterialModifications(MessageCursor<AssetEvent<Material>>);
+
+impl MaterialModifications {
+    pub fn new(world: &World) -> Self {
+        Self(
+            world
+                .resource::<Messages<AssetEvent<Material>>>()
+                .get_cursor_current(),
+        )
+    }
+
+    /// Returns the number of modifications since the last call
+    pub fn read(&mut self, world: &World) -> usize {
+        self.0
+            .read(world.resource::<Messages<AssetEvent<Material>>>())
+            .filter(|message| matches!(message, AssetEvent::Modified { .. }))
+            .count()
+    }
+}
@@ -0,0 +1,81 @@
+//! Headless test harness running the plugin systems without a GPU
+
+#![allow(dead_code)]
+
+use bevy::prelude::*;
+use bevy_health_bar3d::prelude::{HealthBarPlugin, Percentage};
+use bevy_health_bar3d::testing::{material_values, MaterialValues};
+
+#[cfg(feature = "3d")]
+pub type MeshComponent = Mesh3d;
+#[cfg(feature = "2d")]
+pub type MeshComponent = Mesh2d;
+
+#[derive(Component, Reflect)]
+pub struct Health {
+    pub max: f32,
+    pub current: f32,
+}
+
+impl Health {
+    pub fn new(current: f32, max: f32) -> Self {
+        Self { max, current }
+    }
+}
+
+impl Percentage for Health {
+    fn value(&self) -> f32 {
+        self.current / self.max
+    }
+}
+
+#[derive(Component, Reflect)]
+pub struct Mana(pub f32);
+
+impl Percentage for Mana {
+    fn value(&self) -> f32 {
+        self.0
+    }
+}
+
+/// Builds an app with [`MinimalPlugins`], transforms and asset support, but without any bar plugins
+pub fn base_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default(), TransformPlugin))
+        .init_asset::<Shader>()
+        .init_asset::<Mesh>()
+        .init_asset::<Image>();
+    app
+}
+
+/// Builds an app tracking [`Health`] and [`Mana`]
+pub fn app() -> App {
+    let mut app = base_app();
+    app.add_plugins((
+        HealthBarPlugin::<Health>::default(),
+        HealthBarPlugin::<Mana>::default(),
+    ));
+    app
+}
+
+/// Returns the bar entity of type `T` attached to the owner
+pub fn bar<T: Percentage + Component + TypePath>(app: &mut App, owner: Entity) -> Entity {
+    app.world()
+        .get::<bevy_health_bar3d::prelude::HasBar<T>>(owner)
+        .expect("owner has no bar")
+        .bar()
+}
+
+/// Returns the material values of a bar
+pub fn material(app: &App, bar: Entity) -> MaterialValues {
+    material_values(app.world(), bar).expect("bar has no material")
+}
+
+/// Returns the mesh handle of a bar
+pub fn mesh(app: &App, bar: Entity) -> Handle<Mesh> {
+    app.world()
+        .get::<MeshComponent>(bar)
+        .expect("bar has no mesh")
+        .0
+        .clone()
+}
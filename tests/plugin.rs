@@ -0,0 +1,1092 @@
+mod common;
+
+use bevy::camera::primitives::Aabb;
+use bevy::prelude::*;
+use bevy_health_bar3d::prelude::{
+    BarAccessibility, BarAnchor, BarAutoPlacement, BarHeight, BarOf, BarPalette, BarSettings,
+    BarStyle, BarStyles, ColorScheme, ForegroundColor, HasBar, HealthBarPlugin,
+};
+
+use common::{app, bar, base_app, material, mesh, Health, Mana};
+
+#[test]
+fn spawns_bar_for_tracked_component() {
+    let mut app = app();
+    let owner = app
+        .world_mut()
+        .spawn((
+            Health::new(5., 10.),
+            BarSettings::<Health> {
+                width: 2.,
+                ..default()
+            },
+        ))
+        .id();
+
+    app.update();
+
+    let bar = bar::<Health>(&mut app, owner);
+    assert_eq!(app.world().get::<ChildOf>(bar).unwrap().parent(), owner);
+    assert_eq!(
+        app.world().get::<BarOf<Health>>(bar).unwrap().owner(),
+        owner
+    );
+
+    let material = material(&app, bar);
+    assert_eq!(material.value_and_dimensions.x, 0.5);
+    assert_eq!(material.value_and_dimensions.y, 2.);
+    assert!(!material.vertical);
+}
+
+#[test]
+fn does_not_spawn_bar_without_settings() {
+    let mut app = app();
+    let owner = app.world_mut().spawn(Health::new(5., 10.)).id();
+
+    app.update();
+
+    assert!(app.world().get::<HasBar<Health>>(owner).is_none());
+}
+
+#[test]
+fn spawns_bar_with_plugin_defaults() {
+    let mut app = base_app();
+    app.add_plugins(
+        HealthBarPlugin::<Health>::new()
+            .settings(BarSettings {
+                width: 3.,
+                ..default()
+            })
+            .color_scheme(ColorScheme::new().background_color(Color::WHITE)),
+    );
+    let owner = app.world_mut().spawn(Health::new(5., 10.)).id();
+    let overridden = app
+        .world_mut()
+        .spawn((
+            Health::new(5., 10.),
+            BarSettings::<Health> {
+                width: 1.,
+                ..default()
+            },
+        ))
+        .id();
+
+    app.update();
+
+    let default_bar = bar::<Health>(&mut app, owner);
+    let overridden_bar = bar::<Health>(&mut app, overridden);
+
+    let default_material = material(&app, default_bar);
+    assert_eq!(default_material.value_and_dimensions.y, 3.);
+    assert_eq!(default_material.background_color, LinearRgba::WHITE);
+    assert_eq!(material(&app, overridden_bar).value_and_dimensions.y, 1.);
+}
+
+#[test]
+fn spawns_one_bar_per_tracked_component() {
+    let mut app = app();
+    let owner = app
+        .world_mut()
+        .spawn((
+            Health::new(5., 10.),
+            BarSettings::<Health>::default(),
+            Mana(0.25),
+            BarSettings::<Mana>::default(),
+        ))
+        .id();
+
+    app.update();
+
+    let health_bar = bar::<Health>(&mut app, owner);
+    let mana_bar = bar::<Mana>(&mut app, owner);
+    assert_ne!(health_bar, mana_bar);
+    assert_eq!(material(&app, health_bar).value_and_dimensions.x, 0.5);
+    assert_eq!(material(&app, mana_bar).value_and_dimensions.x, 0.25);
+}
+
+#[test]
+fn sets_up_shared_resources_once_styles_are_initialized_by_the_app() {
+    let mut app = base_app();
+    app.init_asset::<BarStyle>();
+    let style = app
+        .world_mut()
+        .resource_mut::<Assets<BarStyle>>()
+        .add(BarStyle {
+            width: Some(4.),
+            ..default()
+        });
+    app.add_plugins(HealthBarPlugin::<Health>::default());
+
+    let owner = app
+        .world_mut()
+        .spawn((
+            Health::new(5., 10.),
+            BarSettings::<Health> {
+                style: Some(style),
+                ..default()
+            },
+        ))
+        .id();
+
+    app.update();
+
+    let bar = bar::<Health>(&mut app, owner);
+    assert_eq!(material(&app, bar).value_and_dimensions.y, 4.);
+}
+
+#[test]
+fn applies_modified_styles_and_presets_without_changing_settings() {
+    let mut app = app();
+    let style = app
+        .world_mut()
+        .resource_mut::<Assets<BarStyle>>()
+        .add(BarStyle {
+            width: Some(4.),
+            ..default()
+        });
+    let owner = app
+        .world_mut()
+        .spawn((
+            Health::new(5., 10.),
+            BarSettings::<Health> {
+                style: Some(style.clone()),
+                preset: Some("wide".into()),
+                ..default()
+            },
+        ))
+        .id();
+
+    app.update();
+    app.update();
+
+    let last_changed = |app: &App| {
+        app.world()
+            .entity(owner)
+            .get_ref::<BarSettings<Health>>()
+            .unwrap()
+            .last_changed()
+    };
+    let settings_changed = last_changed(&app);
+    let bar = bar::<Health>(&mut app, owner);
+    assert_eq!(material(&app, bar).value_and_dimensions.y, 4.);
+
+    app.world_mut()
+        .resource_mut::<Assets<BarStyle>>()
+        .get_mut(&style)
+        .unwrap()
+        .width = Some(6.);
+    app.update();
+    app.update();
+
+    assert_eq!(material(&app, bar).value_and_dimensions.y, 6.);
+
+    let preset = app
+        .world_mut()
+        .resource_mut::<Assets<BarStyle>>()
+        .add(BarStyle {
+            height: Some(BarHeight::Static(0.5)),
+            ..default()
+        });
+    app.world_mut()
+        .resource_mut::<BarStyles>()
+        .insert("wide", preset);
+    app.update();
+
+    assert_eq!(material(&app, bar).value_and_dimensions.z, 0.5);
+    assert_eq!(last_changed(&app), settings_changed);
+}
+
+#[test]
+fn updates_value_when_component_changes() {
+    let mut app = app();
+    let owner = app
+        .world_mut()
+        .spawn((Health::new(10., 10.), BarSettings::<Health>::default()))
+        .id();
+
+    app.update();
+    app.world_mut().get_mut::<Health>(owner).unwrap().current = 2.5;
+    app.update();
+
+    let bar = bar::<Health>(&mut app, owner);
+    assert_eq!(material(&app, bar).value_and_dimensions.x, 0.25);
+}
+
+#[test]
+fn updates_material_and_mesh_when_settings_change() {
+    let mut app = app();
+    let owner = app
+        .world_mut()
+        .spawn((Health::new(10., 10.), BarSettings::<Health>::default()))
+        .id();
+
+    app.update();
+    let bar = bar::<Health>(&mut app, owner);
+    let previous_mesh = mesh(&app, bar);
+
+    {
+        let mut settings = app
+            .world_mut()
+            .get_mut::<BarSettings<Health>>(owner)
+            .unwrap();
+        settings.width = 3.;
+        settings.offset = 1.5;
+        settings.border.width = 0.1;
+    }
+    app.update();
+
+    let material = material(&app, bar);
+    assert_eq!(material.value_and_dimensions.y, 3.);
+    assert_eq!(material.value_and_dimensions.w, 0.1);
+    assert_eq!(material.offset.y, 1.5);
+    assert_ne!(mesh(&app, bar), previous_mesh);
+}
+
+#[test]
+fn shares_meshes_between_bars_of_equal_size() {
+    let mut app = app();
+    let settings = || BarSettings::<Health> {
+        width: 1.5,
+        ..default()
+    };
+    let first = app
+        .world_mut()
+        .spawn((Health::new(1., 10.), settings()))
+        .id();
+    let second = app
+        .world_mut()
+        .spawn((Health::new(9., 10.), settings()))
+        .id();
+
+    app.update();
+
+    let meshes = app.world().resource::<Assets<Mesh>>().len();
+    let first_bar = bar::<Health>(&mut app, first);
+    let second_bar = bar::<Health>(&mut app, second);
+    assert_eq!(mesh(&app, first_bar), mesh(&app, second_bar));
+
+    let third = app
+        .world_mut()
+        .spawn((Health::new(5., 10.), settings()))
+        .id();
+    app.update();
+
+    let third_bar = bar::<Health>(&mut app, third);
+    assert_eq!(mesh(&app, third_bar), mesh(&app, first_bar));
+    assert_eq!(app.world().resource::<Assets<Mesh>>().len(), meshes);
+}
+
+#[test]
+fn despawns_bar_when_component_is_removed() {
+    let mut app = app();
+    let owner = app
+        .world_mut()
+        .spawn((Health::new(10., 10.), BarSettings::<Health>::default()))
+        .id();
+
+    app.update();
+    let bar = bar::<Health>(&mut app, owner);
+
+    app.world_mut().entity_mut(owner).remove::<Health>();
+    app.update();
+
+    assert!(app.world().get_entity(bar).is_err());
+    assert!(app.world().get::<HasBar<Health>>(owner).is_none());
+}
+
+#[test]
+fn despawns_bar_with_owner() {
+    let mut app = app();
+    let owner = app
+        .world_mut()
+        .spawn((Health::new(10., 10.), BarSettings::<Health>::default()))
+        .id();
+
+    app.update();
+    let bar = bar::<Health>(&mut app, owner);
+
+    app.world_mut().entity_mut(owner).despawn();
+    app.update();
+
+    assert!(app.world().get_entity(bar).is_err());
+}
+
+#[test]
+fn compensates_rotation_of_owner() {
+    let mut app = app();
+    let rotation = Quat::from_rotation_y(1.2);
+    let owner = app
+        .world_mut()
+        .spawn((
+            Transform::from_rotation(rotation),
+            Health::new(10., 10.),
+            BarSettings::<Health>::default(),
+        ))
+        .id();
+
+    app.update();
+
+    let bar = bar::<Health>(&mut app, owner);
+    let transform = app.world().get::<Transform>(bar).unwrap();
+    assert!(transform.rotation.abs_diff_eq(rotation.inverse(), 1e-6));
+}
+
+#[test]
+fn applies_world_space_offset_to_translation() {
+    use bevy_health_bar3d::prelude::BarOffsetMode;
+
+    let mut app = app();
+    let rotation = Quat::from_rotation_z(0.5);
+    let owner = app
+        .world_mut()
+        .spawn((
+            Transform::from_rotation(rotation),
+            Health::new(10., 10.),
+            BarSettings::<Health> {
+                offset: 2.,
+                offset_mode: BarOffsetMode::WorldSpace,
+                ..default()
+            },
+        ))
+        .id();
+
+    app.update();
+
+    let bar = bar::<Health>(&mut app, owner);
+    let transform = *app.world().get::<Transform>(bar).unwrap();
+    assert!((rotation * transform.translation).abs_diff_eq(Vec3::new(0., 2., 0.), 1e-5));
+    assert!(material(&app, bar).world_space_offset);
+
+    app.world_mut()
+        .get_mut::<BarSettings<Health>>(owner)
+        .unwrap()
+        .offset_mode = BarOffsetMode::CameraSpace;
+    app.update();
+
+    let transform = app.world().get::<Transform>(bar).unwrap();
+    assert_eq!(transform.translation, Vec3::ZERO);
+    assert!(!material(&app, bar).world_space_offset);
+}
+
+#[test]
+fn writes_messages_when_crossing_thresholds() {
+    use bevy::ecs::message::Messages;
+    use bevy_health_bar3d::prelude::{BarBand, BarEmptied, BarThresholdCrossed};
+
+    let mut app = app();
+    let owner = app
+        .world_mut()
+        .spawn((Health::new(10., 10.), BarSettings::<Health>::default()))
+        .id();
+
+    app.update();
+    app.world_mut().get_mut::<Health>(owner).unwrap().current = 0.;
+    app.update();
+
+    let crossed = app
+        .world()
+        .resource::<Messages<BarThresholdCrossed<Health>>>();
+    let crossed: Vec<_> = crossed.iter_current_update_messages().collect();
+    assert_eq!(crossed.len(), 1);
+    assert_eq!(crossed[0].entity, owner);
+    assert_eq!(crossed[0].to, BarBand::Low);
+
+    let emptied = app.world().resource::<Messages<BarEmptied<Health>>>();
+    assert_eq!(emptied.iter_current_update_messages().count(), 1);
+}
+
+#[test]
+fn recolors_bars_when_color_scheme_changes() {
+    let mut app = app();
+    let owner = app
+        .world_mut()
+        .spawn((Health::new(5., 10.), BarSettings::<Health>::default()))
+        .id();
+
+    app.update();
+
+    app.insert_resource(
+        ColorScheme::<Health>::new()
+            .foreground_color(ForegroundColor::Static(Color::srgb(1., 0., 0.)))
+            .background_color(Color::WHITE),
+    );
+    app.update();
+
+    let bar = bar::<Health>(&mut app, owner);
+    let material = material(&app, bar);
+    assert_eq!(material.high_color, Color::srgb(1., 0., 0.).into());
+    assert_eq!(material.low_color, Color::srgb(1., 0., 0.).into());
+    assert_eq!(material.background_color, LinearRgba::WHITE);
+}
+
+#[test]
+fn swaps_palettes_when_accessibility_changes() {
+    let mut app = app();
+    let owner = app
+        .world_mut()
+        .spawn((Health::new(5., 10.), BarSettings::<Health>::default()))
+        .id();
+
+    app.update();
+
+    let bar = bar::<Health>(&mut app, owner);
+    let scheme_material = material(&app, bar);
+
+    app.insert_resource(BarAccessibility {
+        palette: Some(BarPalette::HighContrast),
+        ..default()
+    });
+    app.update();
+
+    let ForegroundColor::TriSpectrum {
+        high,
+        moderate,
+        low,
+    } = BarPalette::HighContrast.foreground_color()
+    else {
+        panic!("palette has no tri-color spectrum");
+    };
+    let palette_material = material(&app, bar);
+    assert_eq!(palette_material.high_color, high.into());
+    assert_eq!(palette_material.moderate_color, moderate.into());
+    assert_eq!(palette_material.low_color, low.into());
+    assert_eq!(
+        palette_material.background_color,
+        BarPalette::HighContrast.background_color().into()
+    );
+
+    app.insert_resource(BarAccessibility::default());
+    app.update();
+
+    let material = material(&app, bar);
+    assert_eq!(material.high_color, scheme_material.high_color);
+    assert_eq!(material.background_color, scheme_material.background_color);
+}
+
+#[test]
+fn leaves_colors_of_bars_overriding_both_colors_untouched() {
+    let mut app = app();
+    let owner = app
+        .world_mut()
+        .spawn((
+            Health::new(5., 10.),
+            BarSettings::<Health> {
+                foreground_color: Some(ForegroundColor::Static(Color::srgb(0., 1., 0.))),
+                background_color: Some(Color::BLACK),
+                ..default()
+            },
+        ))
+        .id();
+
+    app.update();
+
+    let bar = bar::<Health>(&mut app, owner);
+    let overridden = material(&app, bar);
+
+    app.insert_resource(
+        ColorScheme::<Health>::new()
+            .foreground_color(ForegroundColor::Static(Color::srgb(1., 0., 0.)))
+            .background_color(Color::WHITE),
+    );
+    app.insert_resource(BarAccessibility {
+        palette: Some(BarPalette::HighContrast),
+        ..default()
+    });
+    app.update();
+
+    let material = material(&app, bar);
+    assert_eq!(material.high_color, Color::srgb(0., 1., 0.).into());
+    assert_eq!(material.high_color, overridden.high_color);
+    assert_eq!(material.low_color, overridden.low_color);
+    assert_eq!(material.background_color, LinearRgba::BLACK);
+}
+
+#[test]
+fn spawns_and_despawns_icon() {
+    use bevy_health_bar3d::prelude::BarIcon;
+
+    let mut app = app();
+    let owner = app
+        .world_mut()
+        .spawn((
+            Health::new(10., 10.),
+            BarSettings::<Health> {
+                icon: Some(BarIcon::new(Handle::default(), 0.2)),
+                ..default()
+            },
+        ))
+        .id();
+
+    app.update();
+
+    let bar = bar::<Health>(&mut app, owner);
+    let children: Vec<Entity> = app
+        .world()
+        .get::<Children>(bar)
+        .expect("bar has no children")
+        .to_vec();
+    assert_eq!(children.len(), 1);
+    let icon = material(&app, children[0]);
+    assert!(icon.icon.is_some());
+    assert_eq!(icon.value_and_dimensions.y, 0.2);
+
+    app.world_mut()
+        .get_mut::<BarSettings<Health>>(owner)
+        .unwrap()
+        .icon = None;
+    app.update();
+
+    assert!(app.world().get_entity(children[0]).is_err());
+}
+
+#[test]
+fn compensates_transform_of_nested_owner() {
+    let mut app = app();
+    let parent = app
+        .world_mut()
+        .spawn(Transform::from_xyz(1., 2., 3.).with_rotation(Quat::from_rotation_x(0.7)))
+        .id();
+    let owner = app
+        .world_mut()
+        .spawn((
+            Transform::from_rotation(Quat::from_rotation_y(1.2)),
+            Health::new(10., 10.),
+            BarSettings::<Health>::default(),
+            ChildOf(parent),
+        ))
+        .id();
+
+    app.update();
+
+    let bar = bar::<Health>(&mut app, owner);
+    let owner_transform = app.world().get::<GlobalTransform>(owner).unwrap();
+    let bar_transform = app.world().get::<GlobalTransform>(bar).unwrap();
+    assert!(bar_transform.rotation().abs_diff_eq(Quat::IDENTITY, 1e-5));
+    assert!(bar_transform
+        .translation()
+        .abs_diff_eq(owner_transform.translation(), 1e-5));
+}
+
+#[test]
+fn scales_bar_with_owner_unless_ignored() {
+    let mut app = app();
+    let owner = app
+        .world_mut()
+        .spawn((
+            Transform::from_rotation(Quat::from_rotation_y(0.4)).with_scale(Vec3::splat(2.)),
+            Health::new(10., 10.),
+            BarSettings::<Health>::default(),
+        ))
+        .id();
+
+    app.update();
+
+    let bar = bar::<Health>(&mut app, owner);
+    let scale = app.world().get::<GlobalTransform>(bar).unwrap().scale();
+    assert!(scale.abs_diff_eq(Vec3::splat(2.), 1e-5));
+
+    app.world_mut()
+        .get_mut::<BarSettings<Health>>(owner)
+        .unwrap()
+        .ignore_parent_scale = true;
+    app.update();
+
+    let bar_transform = app.world().get::<GlobalTransform>(bar).unwrap();
+    assert!(bar_transform.scale().abs_diff_eq(Vec3::ONE, 1e-5));
+    assert!(bar_transform.rotation().abs_diff_eq(Quat::IDENTITY, 1e-5));
+}
+
+#[test]
+fn rotates_fixed_bar_with_owner() {
+    use bevy_health_bar3d::prelude::BarBillboardMode;
+
+    let mut app = app();
+    let parent = app
+        .world_mut()
+        .spawn(Transform::from_rotation(Quat::from_rotation_z(0.3)))
+        .id();
+    let owner = app
+        .world_mut()
+        .spawn((
+            Transform::from_rotation(Quat::from_rotation_y(1.2)),
+            Health::new(10., 10.),
+            BarSettings::<Health> {
+                billboard_mode: BarBillboardMode::Fixed,
+                ..default()
+            },
+            ChildOf(parent),
+        ))
+        .id();
+
+    app.update();
+
+    let bar = bar::<Health>(&mut app, owner);
+    let owner_rotation = app
+        .world()
+        .get::<GlobalTransform>(owner)
+        .unwrap()
+        .rotation();
+    let bar_rotation = app.world().get::<GlobalTransform>(bar).unwrap().rotation();
+    assert!(bar_rotation.abs_diff_eq(owner_rotation, 1e-5));
+}
+
+#[test]
+fn places_bar_above_bounds_of_owner_and_descendants() {
+    let mut app = app();
+    let owner = app
+        .world_mut()
+        .spawn((
+            Health::new(5., 10.),
+            BarSettings::<Health> {
+                height: BarHeight::Static(0.2),
+                auto_placement: Some(BarAutoPlacement::new(0.2).fit_width(1.)),
+                // placed fields take precedence over overrides
+                overrides: BarStyle {
+                    offset: Some(5.),
+                    width: Some(3.),
+                    ..default()
+                },
+                ..default()
+            },
+            Aabb::from_min_max(Vec3::new(-0.5, -1., -0.5), Vec3::new(0.5, 1., 0.5)),
+            Transform::default(),
+        ))
+        .id();
+    let head = app
+        .world_mut()
+        .spawn((
+            Transform::from_xyz(0., 2., 0.),
+            Aabb::from_min_max(Vec3::splat(-0.5), Vec3::splat(0.5)),
+            ChildOf(owner),
+        ))
+        .id();
+
+    // the bounds are placed once transforms have been propagated
+    app.update();
+    app.update();
+
+    let bar = bar::<Health>(&mut app, owner);
+    let settings = app.world().get::<BarSettings<Health>>(owner).unwrap();
+    assert!((settings.offset - 2.8).abs() < 1e-5);
+    assert!((settings.width - 1.).abs() < 1e-5);
+    assert_eq!(material(&app, bar).value_and_dimensions.y, settings.width);
+    assert_eq!(material(&app, bar).offset.y, settings.offset);
+
+    *app.world_mut().get_mut::<Aabb>(head).unwrap() =
+        Aabb::from_min_max(Vec3::new(-1., -0.5, -0.5), Vec3::new(1., 1.5, 0.5));
+    app.update();
+
+    let settings = app.world().get::<BarSettings<Health>>(owner).unwrap();
+    assert!((settings.offset - 3.8).abs() < 1e-5);
+    assert!((settings.width - 2.).abs() < 1e-5);
+}
+
+#[test]
+fn places_bar_again_only_when_hierarchy_of_owner_changes() {
+    let mut app = app();
+    let owner = app
+        .world_mut()
+        .spawn((
+            Health::new(5., 10.),
+            BarSettings::<Health> {
+                auto_placement: Some(BarAutoPlacement::new(0.2)),
+                ..default()
+            },
+            Aabb::from_min_max(Vec3::splat(-0.5), Vec3::splat(0.5)),
+            Transform::default(),
+        ))
+        .id();
+    let child = app
+        .world_mut()
+        .spawn((Transform::default(), ChildOf(owner)))
+        .id();
+
+    app.update();
+    app.update();
+
+    let placed_offset = app
+        .world()
+        .get::<BarSettings<Health>>(owner)
+        .unwrap()
+        .offset;
+    app.world_mut()
+        .get_mut::<BarSettings<Health>>(owner)
+        .unwrap()
+        .bypass_change_detection()
+        .offset = 10.;
+    app.update();
+
+    let settings = app.world().get::<BarSettings<Health>>(owner).unwrap();
+    assert_eq!(settings.offset, 10.);
+
+    app.world_mut()
+        .entity_mut(child)
+        .insert(Aabb::from_min_max(Vec3::splat(-0.5), Vec3::splat(0.5)));
+    app.update();
+
+    let settings = app.world().get::<BarSettings<Health>>(owner).unwrap();
+    assert!((settings.offset - placed_offset).abs() < 1e-5);
+}
+
+#[test]
+fn follows_named_anchor_once_spawned() {
+    let mut app = app();
+    let owner = app
+        .world_mut()
+        .spawn((
+            Health::new(5., 10.),
+            BarSettings::<Health> {
+                anchor: Some(BarAnchor::Name("Head".into())),
+                ..default()
+            },
+            Transform::from_xyz(1., 0., 0.),
+        ))
+        .id();
+
+    app.update();
+    let bar = bar::<Health>(&mut app, owner);
+    let translation = |app: &App| {
+        app.world()
+            .get::<GlobalTransform>(bar)
+            .unwrap()
+            .translation()
+    };
+    assert_eq!(translation(&app), Vec3::new(1., 0., 0.));
+
+    // spawned later, like the nodes of a glTF scene. The scale of the armature is not applied to the bar.
+    let body = app
+        .world_mut()
+        .spawn((
+            Transform::from_xyz(0., 1., 0.).with_scale(Vec3::splat(2.)),
+            ChildOf(owner),
+        ))
+        .id();
+    let head = app
+        .world_mut()
+        .spawn((
+            Name::new("Head"),
+            Transform::from_xyz(0., 1., 0.5),
+            ChildOf(body),
+        ))
+        .id();
+    app.update();
+    assert_eq!(translation(&app), Vec3::new(1., 3., 1.));
+    let scale = app.world().get::<GlobalTransform>(bar).unwrap().scale();
+    assert!(scale.abs_diff_eq(Vec3::ONE, 1e-5));
+
+    app.world_mut()
+        .get_mut::<Transform>(head)
+        .unwrap()
+        .translation
+        .y = 0.5;
+    app.update();
+    assert_eq!(translation(&app), Vec3::new(1., 2., 1.));
+}
+
+#[test]
+fn inherits_render_layers_of_owner_without_changing_its_settings() {
+    use bevy::camera::visibility::RenderLayers;
+
+    let mut app = app();
+    let owner = app
+        .world_mut()
+        .spawn((Health::new(5., 10.), BarSettings::<Health>::default()))
+        .id();
+
+    app.update();
+    app.update();
+
+    let last_changed = |app: &App| {
+        app.world()
+            .entity(owner)
+            .get_ref::<BarSettings<Health>>()
+            .unwrap()
+            .last_changed()
+    };
+    let settings_changed = last_changed(&app);
+    let bar = bar::<Health>(&mut app, owner);
+    assert!(app.world().get::<RenderLayers>(bar).is_none());
+
+    app.world_mut()
+        .entity_mut(owner)
+        .insert(RenderLayers::layer(2));
+    app.update();
+
+    assert_eq!(
+        app.world().get::<RenderLayers>(bar),
+        Some(&RenderLayers::layer(2))
+    );
+    assert_eq!(last_changed(&app), settings_changed);
+
+    app.world_mut().entity_mut(owner).remove::<RenderLayers>();
+    app.update();
+
+    assert!(app.world().get::<RenderLayers>(bar).is_none());
+    assert_eq!(last_changed(&app), settings_changed);
+}
+
+#[cfg(feature = "3d")]
+#[test]
+fn applies_effects_of_presets_styles_and_overrides() {
+    use bevy_health_bar3d::prelude::{BarBillboardMode, BarEdgeClamp, BarOcclusion};
+
+    let mut app = app();
+    let preset = app
+        .world_mut()
+        .resource_mut::<Assets<BarStyle>>()
+        .add(BarStyle {
+            billboard_mode: Some(BarBillboardMode::Cylindrical),
+            occlusion: Some(BarOcclusion::AlwaysOnTop),
+            depth_bias: Some(1.),
+            ..default()
+        });
+    app.world_mut()
+        .resource_mut::<BarStyles>()
+        .insert("elite", preset);
+    let style = app
+        .world_mut()
+        .resource_mut::<Assets<BarStyle>>()
+        .add(BarStyle {
+            depth_bias: Some(2.),
+            edge_clamp: Some(BarEdgeClamp::new(12.)),
+            ..default()
+        });
+
+    let owner = app
+        .world_mut()
+        .spawn((
+            Health::new(5., 10.),
+            BarSettings::<Health> {
+                preset: Some("elite".into()),
+                style: Some(style),
+                overrides: BarStyle {
+                    billboard_mode: Some(BarBillboardMode::Fixed),
+                    ..default()
+                },
+                ..default()
+            },
+        ))
+        .id();
+
+    app.update();
+
+    let bar = bar::<Health>(&mut app, owner);
+    let material = material(&app, bar);
+    assert_eq!(material.billboard_mode, BarBillboardMode::Fixed);
+    assert_eq!(material.occlusion, BarOcclusion::AlwaysOnTop);
+    assert_eq!(material.depth_bias, 2.);
+    assert!(material.clamp_to_edge);
+    assert_eq!(material.edge_clamp.x, 12.);
+}
+
+#[cfg(feature = "3d")]
+#[test]
+fn spawns_arrow_and_disables_culling_of_edge_clamped_bars() {
+    use bevy::camera::visibility::NoFrustumCulling;
+    use bevy_health_bar3d::prelude::BarEdgeClamp;
+
+    let mut app = app();
+    let owner = app
+        .world_mut()
+        .spawn((
+            Health::new(10., 10.),
+            BarSettings::<Health> {
+                edge_clamp: Some(BarEdgeClamp::new(8.).arrow(Color::WHITE)),
+                ..default()
+            },
+        ))
+        .id();
+
+    app.update();
+
+    let bar = bar::<Health>(&mut app, owner);
+    assert!(app.world().get::<NoFrustumCulling>(bar).is_some());
+    let bar_material = material(&app, bar);
+    assert!(bar_material.clamp_to_edge);
+    assert_eq!(bar_material.edge_clamp.x, 8.);
+
+    let children: Vec<Entity> = app
+        .world()
+        .get::<Children>(bar)
+        .expect("bar has no children")
+        .to_vec();
+    assert_eq!(children.len(), 1);
+    let arrow = material(&app, children[0]);
+    assert!(arrow.arrow);
+    assert_eq!(arrow.edge_clamp, bar_material.edge_clamp);
+
+    app.world_mut()
+        .get_mut::<BarSettings<Health>>(owner)
+        .unwrap()
+        .edge_clamp = None;
+    app.update();
+
+    assert!(app.world().get::<NoFrustumCulling>(bar).is_none());
+    assert!(!material(&app, bar).clamp_to_edge);
+    assert!(app.world().get_entity(children[0]).is_err());
+}
+
+#[cfg(feature = "3d")]
+#[test]
+fn respawns_arrow_with_bar_and_shares_arrow_meshes() {
+    use bevy_health_bar3d::prelude::BarEdgeClamp;
+
+    fn arrow(app: &mut App, owner: Entity) -> Option<Entity> {
+        let bar = bar::<Health>(app, owner);
+        app.world()
+            .get::<Children>(bar)
+            .and_then(|children| children.first().copied())
+    }
+
+    let mut app = app();
+    let settings = || BarSettings::<Health> {
+        edge_clamp: Some(BarEdgeClamp::default().arrow(Color::WHITE)),
+        ..default()
+    };
+    let owner = app
+        .world_mut()
+        .spawn((Health::new(10., 10.), settings()))
+        .id();
+    let other = app
+        .world_mut()
+        .spawn((Health::new(10., 10.), settings()))
+        .id();
+
+    app.update();
+
+    let arrows = (
+        arrow(&mut app, owner).expect("bar has no arrow"),
+        arrow(&mut app, other).expect("bar has no arrow"),
+    );
+    assert_eq!(mesh(&app, arrows.0), mesh(&app, arrows.1));
+
+    app.world_mut().entity_mut(owner).remove::<Health>();
+    app.update();
+    app.world_mut()
+        .entity_mut(owner)
+        .insert(Health::new(5., 10.));
+    app.update();
+
+    let arrow = arrow(&mut app, owner).expect("respawned bar has no arrow");
+    assert!(material(&app, arrow).arrow);
+}
+
+#[cfg(feature = "3d")]
+#[test]
+fn leaves_materials_untouched_when_camera_settings_change() {
+    use bevy_health_bar3d::prelude::HealthBarCameraSettings;
+    use bevy_health_bar3d::testing::MaterialModifications;
+
+    let mut app = app();
+    let owner = app
+        .world_mut()
+        .spawn((Health::new(10., 10.), BarSettings::<Health>::default()))
+        .id();
+    app.update();
+    app.update();
+
+    let mut modifications = MaterialModifications::new(app.world());
+    let camera = app
+        .world_mut()
+        .spawn((Camera3d::default(), HealthBarCameraSettings::default()))
+        .id();
+    app.update();
+    app.world_mut()
+        .get_mut::<HealthBarCameraSettings>(camera)
+        .unwrap()
+        .scale = 2.;
+    app.update();
+    app.world_mut().despawn(camera);
+    app.update();
+    assert_eq!(modifications.read(app.world()), 0);
+
+    app.world_mut().get_mut::<Health>(owner).unwrap().current = 5.;
+    app.update();
+    assert_eq!(modifications.read(app.world()), 1);
+}
+
+#[cfg(feature = "overlay")]
+#[test]
+fn draws_overlay_instead_of_rendering_bar_in_screen_overlay_mode() {
+    use bevy::camera::visibility::RenderLayers;
+    use bevy_health_bar3d::prelude::BarRenderMode;
+
+    fn overlays(app: &mut App) -> Vec<(Node, Vec<Entity>)> {
+        app.world_mut()
+            .query_filtered::<(&Node, &Children), With<BackgroundColor>>()
+            .iter(app.world())
+            .map(|(node, children)| (node.clone(), children.to_vec()))
+            .collect()
+    }
+
+    let mut app = app();
+    let owner = app
+        .world_mut()
+        .spawn((
+            Health::new(5., 10.),
+            BarSettings::<Health> {
+                width: 80.,
+                height: BarHeight::Static(10.),
+                render_mode: BarRenderMode::ScreenOverlay { camera: None },
+                ..default()
+            },
+        ))
+        .id();
+
+    app.update();
+
+    let bar = bar::<Health>(&mut app, owner);
+    assert_eq!(
+        app.world().get::<RenderLayers>(bar),
+        Some(&RenderLayers::none())
+    );
+
+    let spawned = overlays(&mut app);
+    assert_eq!(spawned.len(), 1);
+    let (node, children) = &spawned[0];
+    assert_eq!((node.width, node.height), (Val::Px(80.), Val::Px(10.)));
+    let fill = app.world().get::<Node>(children[0]).unwrap();
+    assert_eq!(fill.width, Val::Percent(50.));
+
+    app.world_mut()
+        .get_mut::<BarSettings<Health>>(owner)
+        .unwrap()
+        .render_mode = BarRenderMode::Billboard;
+    app.update();
+
+    assert!(app.world().get::<RenderLayers>(bar).is_none());
+    assert!(overlays(&mut app).is_empty());
+}
+
+#[cfg(feature = "overlay")]
+#[test]
+fn respawns_overlay_when_component_is_re_added() {
+    use bevy_health_bar3d::prelude::BarRenderMode;
+
+    let mut app = app();
+    let owner = app
+        .world_mut()
+        .spawn((
+            Health::new(5., 10.),
+            BarSettings::<Health> {
+                render_mode: BarRenderMode::ScreenOverlay { camera: None },
+                ..default()
+            },
+        ))
+        .id();
+
+    app.update();
+    app.world_mut().entity_mut(owner).remove::<Health>();
+    app.update();
+    app.world_mut()
+        .entity_mut(owner)
+        .insert(Health::new(5., 10.));
+    app.update();
+
+    let overlays = app
+        .world_mut()
+        .query_filtered::<(), (With<Node>, With<Children>)>()
+        .iter(app.world())
+        .count();
+    assert_eq!(overlays, 1);
+}
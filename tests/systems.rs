@@ -0,0 +1,180 @@
+mod common;
+
+use bevy::ecs::schedule::ScheduleLabel;
+use bevy::prelude::*;
+use bevy_health_bar3d::prelude::{
+    BarSettings, HasBar, HealthBarGlobalSettings, HealthBarPlugin, HealthBarSystems,
+};
+
+use common::{app, bar, base_app, material, Health, Mana};
+
+#[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+struct BarSchedule;
+
+#[test]
+fn runs_in_configured_schedule() {
+    let mut app = base_app();
+    app.add_plugins(HealthBarPlugin::<Health>::new().in_schedule(BarSchedule));
+    let owner = app
+        .world_mut()
+        .spawn((Health::new(5., 10.), BarSettings::<Health>::default()))
+        .id();
+
+    app.update();
+    assert!(app.world().get::<HasBar<Health>>(owner).is_none());
+
+    app.world_mut().run_schedule(BarSchedule);
+    let bar = bar::<Health>(&mut app, owner);
+    assert_eq!(material(&app, bar).value_and_dimensions.x, 0.5);
+}
+
+#[test]
+fn syncs_after_systems_ordered_before_sync_set() {
+    fn damage(mut query: Query<&mut Health>) {
+        query.iter_mut().for_each(|mut health| health.current -= 1.);
+    }
+
+    let mut app = base_app();
+    app.add_plugins(HealthBarPlugin::<Health>::new())
+        .add_systems(Update, damage.before(HealthBarSystems::Sync));
+    let owner = app
+        .world_mut()
+        .spawn((Health::new(10., 10.), BarSettings::<Health>::default()))
+        .id();
+
+    app.update();
+    let bar = bar::<Health>(&mut app, owner);
+    app.update();
+
+    assert_eq!(material(&app, bar).value_and_dimensions.x, 0.8);
+}
+
+#[test]
+fn runs_only_if_conditions_are_met() {
+    #[derive(Resource)]
+    struct Cutscene;
+
+    let mut app = base_app();
+    app.insert_resource(Cutscene)
+        .add_plugins(HealthBarPlugin::<Health>::new().run_if(not(resource_exists::<Cutscene>)));
+    let owner = app
+        .world_mut()
+        .spawn((Health::new(5., 10.), BarSettings::<Health>::default()))
+        .id();
+
+    app.update();
+    assert!(app.world().get::<HasBar<Health>>(owner).is_none());
+
+    app.world_mut().remove_resource::<Cutscene>();
+    app.update();
+    assert!(app.world().get::<HasBar<Health>>(owner).is_some());
+}
+
+#[test]
+fn freezes_bars_while_paused() {
+    let mut app = app();
+    let owner = app
+        .world_mut()
+        .spawn((Health::new(10., 10.), BarSettings::<Health>::default()))
+        .id();
+
+    app.update();
+    let bar = bar::<Health>(&mut app, owner);
+
+    app.world_mut()
+        .resource_mut::<HealthBarGlobalSettings>()
+        .paused = true;
+    app.world_mut().get_mut::<Health>(owner).unwrap().current = 5.;
+    app.update();
+    assert_eq!(material(&app, bar).value_and_dimensions.x, 1.);
+
+    app.world_mut()
+        .resource_mut::<HealthBarGlobalSettings>()
+        .paused = false;
+    app.update();
+    assert_eq!(material(&app, bar).value_and_dimensions.x, 0.5);
+}
+
+#[test]
+fn hides_bars_of_all_types_and_keeps_hidden_bars_hidden() {
+    let mut app = app();
+    let owner = app
+        .world_mut()
+        .spawn((
+            Health::new(5., 10.),
+            Mana(0.5),
+            BarSettings::<Health>::default(),
+            BarSettings::<Mana>::default(),
+        ))
+        .id();
+
+    app.update();
+    let health_bar = bar::<Health>(&mut app, owner);
+    let mana_bar = bar::<Mana>(&mut app, owner);
+    *app.world_mut().get_mut::<Visibility>(mana_bar).unwrap() = Visibility::Hidden;
+
+    app.world_mut()
+        .resource_mut::<HealthBarGlobalSettings>()
+        .visible = false;
+    app.update();
+    assert_eq!(
+        app.world().get::<Visibility>(health_bar),
+        Some(&Visibility::Hidden)
+    );
+    assert_eq!(
+        app.world().get::<Visibility>(mana_bar),
+        Some(&Visibility::Hidden)
+    );
+
+    app.world_mut()
+        .resource_mut::<HealthBarGlobalSettings>()
+        .visible = true;
+    app.update();
+    assert_eq!(
+        app.world().get::<Visibility>(health_bar),
+        Some(&Visibility::Inherited)
+    );
+    assert_eq!(
+        app.world().get::<Visibility>(mana_bar),
+        Some(&Visibility::Hidden)
+    );
+}
+
+#[test]
+fn restores_visibility_set_before_or_while_hidden() {
+    let mut app = app();
+    let owner = app
+        .world_mut()
+        .spawn((
+            Health::new(5., 10.),
+            Mana(0.5),
+            BarSettings::<Health>::default(),
+            BarSettings::<Mana>::default(),
+        ))
+        .id();
+
+    app.update();
+    let health_bar = bar::<Health>(&mut app, owner);
+    let mana_bar = bar::<Mana>(&mut app, owner);
+    *app.world_mut().get_mut::<Visibility>(health_bar).unwrap() = Visibility::Visible;
+
+    app.world_mut()
+        .resource_mut::<HealthBarGlobalSettings>()
+        .visible = false;
+    app.update();
+    *app.world_mut().get_mut::<Visibility>(mana_bar).unwrap() = Visibility::Hidden;
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<HealthBarGlobalSettings>()
+        .visible = true;
+    app.update();
+    assert_eq!(
+        app.world().get::<Visibility>(health_bar),
+        Some(&Visibility::Visible)
+    );
+    assert_eq!(
+        app.world().get::<Visibility>(mana_bar),
+        Some(&Visibility::Hidden)
+    );
+}